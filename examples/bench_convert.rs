@@ -0,0 +1,80 @@
+//! A quick-and-dirty benchmark for bulk conversions.
+//!
+//! Run it in release mode, or the numbers won't mean much:
+//!
+//! ```sh
+//! cargo run --release --example bench_convert
+//! ```
+
+use simmer::{convert::convert_raw_slice, Temperature, Unit};
+use std::{hint::black_box, time::Instant};
+
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+const LEN: usize = 1_000_000;
+const ROUNDS: u32 = 100;
+
+fn main() {
+    let values: Vec<Float> = (0..LEN).map(|i| (i % 1000) as Float * 0.1).collect();
+    let mut out = vec![0.0; LEN];
+
+    // one `Temperature` at a time
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for (value, out) in values.iter().zip(out.iter_mut()) {
+            *out = Temperature::Celsius(*value).to_fahrenheit().into_inner();
+        }
+        black_box(&mut out);
+    }
+    let wrapped = start.elapsed() / ROUNDS;
+
+    // the whole slice at once
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        convert_raw_slice(
+            black_box(&values),
+            Unit::Celsius,
+            Unit::Fahrenheit,
+            &mut out,
+        );
+        black_box(&mut out);
+    }
+    let raw = start.elapsed() / ROUNDS;
+
+    println!("converting {LEN} values, averaged over {ROUNDS} rounds:");
+    println!("  Temperature::to_fahrenheit: {wrapped:?}");
+    println!("  convert_raw_slice:          {raw:?}");
+
+    #[cfg(all(feature = "checked", std))]
+    bench_checked();
+}
+
+/// Compares a same-unit `CheckedTemperature` conversion (which should return
+/// right away) against one that has to adjust its bounds.
+#[cfg(all(feature = "checked", std))]
+fn bench_checked() {
+    use simmer::checked::CheckedTemperature;
+
+    let mut temp = CheckedTemperature::with_bounds(Temperature::Celsius(21.0), -10.0, 50.0)
+        .expect("the temperature is within its bounds");
+
+    let start = Instant::now();
+    for _ in 0..LEN {
+        black_box(black_box(&mut temp).to_celsius().unwrap());
+    }
+    let same = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..LEN {
+        black_box(black_box(&temp).to_fahrenheit().unwrap());
+    }
+    let different = start.elapsed();
+
+    println!("converting a CheckedTemperature {LEN} times:");
+    println!("  celsius -> celsius:         {same:?}");
+    println!("  celsius -> fahrenheit:      {different:?}");
+}
@@ -4,6 +4,7 @@ use simmer::Temperature;
 extern crate alloc;
 
 // create a String newtype to be a `ufmt` buffer 🤤
+#[allow(dead_code)]
 struct StringBuf(alloc::string::String);
 
 impl ufmt_write::uWrite for StringBuf {
@@ -9,4 +9,7 @@ fuzz_target!(|input: Temperature| {
     if let Ok(mut t) = temp {
         assert!(t.to_kelvin().unwrap().get_inner() >= 0.0);
     }
+
+    // sanitized temperatures should always be accepted
+    assert!(CheckedTemperature::new(input.sanitized()).is_ok());
 });
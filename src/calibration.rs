@@ -0,0 +1,212 @@
+//! # Calibration
+//!
+//! Tools for correcting raw sensor readings.
+//!
+//! Real sensors (and their amplifiers!) are rarely perfect. A thermocouple
+//! amplifier might have a small gain error and offset, for example. These
+//! types help you correct for that when you read a value.
+
+use crate::{Float, Temperature};
+
+/// A linear correction, applied as `value * gain + offset`.
+///
+/// The offset is applied in whatever unit the [Temperature] being calibrated
+/// is in. So, an offset of `1.0` on a Fahrenheit reading adds one degree
+/// Fahrenheit.
+///
+/// The default calibration changes nothing (a gain of `1.0` and an offset of
+/// `0.0`).
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{Calibration, Temperature};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// // my amplifier reads 2% high and 0.5 degrees low
+/// let cal = Calibration { gain: 1.0 / 1.02, offset: 0.5 };
+///
+/// let raw = Temperature::Celsius(51.0);
+/// assert_approx_eq!(raw.calibrate(&cal).into_inner(), 50.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    pub gain: Float,
+    pub offset: Float,
+}
+
+impl Calibration {
+    /// Creates a new [Calibration] from a gain and an offset.
+    pub const fn new(gain: Float, offset: Float) -> Calibration {
+        Calibration { gain, offset }
+    }
+}
+
+impl Default for Calibration {
+    /// A [Calibration] that doesn't change anything.
+    fn default() -> Self {
+        Calibration::new(1.0, 0.0)
+    }
+}
+
+impl Temperature {
+    /// Applies a [Calibration] to this temperature, returning
+    /// `value * gain + offset` in the same unit.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Calibration, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let cal = Calibration::new(2.0, -1.0);
+    /// let fixed = Temperature::Kelvin(150.0).calibrate(&cal);
+    ///
+    /// assert_eq!(fixed, Temperature::Kelvin(299.0));
+    /// ```
+    pub fn calibrate(&self, cal: &Calibration) -> Temperature {
+        Temperature::from((self.get_inner() * cal.gain + cal.offset, self.unit()))
+    }
+
+    /// Maps a sensor's 0–100% output onto a temperature range.
+    ///
+    /// `0.0` maps to `range_lower`, `100.0` maps to `range_upper`, and
+    /// everything in between is linear. `range_upper` is converted into
+    /// `range_lower`'s unit first, and that's the unit you get back.
+    ///
+    /// Percentages outside of `0.0..=100.0` are clamped to the range. A `NaN`
+    /// percentage gives you a `NaN` temperature.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // this sensor reads -40 C at 0% and 125 C at 100%
+    /// let lower = Temperature::Celsius(-40.0);
+    /// let upper = Temperature::Celsius(125.0);
+    ///
+    /// let reading = Temperature::from_percent(40.0, lower, upper);
+    /// assert_approx_eq!(reading.into_inner(), 26.0);
+    /// ```
+    pub fn from_percent(
+        percent: Float,
+        range_lower: Temperature,
+        range_upper: Temperature,
+    ) -> Temperature {
+        let fraction = percent.clamp(0.0, 100.0) / 100.0;
+
+        let lower = range_lower.get_inner();
+        let upper = range_upper.to_unit(range_lower.unit()).get_inner();
+
+        Temperature::from((lower + fraction * (upper - lower), range_lower.unit()))
+    }
+}
+
+/// A fixed-size table that maps sensor voltages to temperatures.
+///
+/// Each entry is a `(voltage, temperature)` pair. Lookups linearly
+/// interpolate between the two entries around the given voltage, and it's all
+/// done without allocating or panicking.
+///
+/// **Important**: entries must be sorted by voltage, from lowest to highest.
+/// Lookups on an unsorted table will give you nonsense!
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{calibration::LookupTable, Temperature};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let table = LookupTable::new([
+///     (0.0, Temperature::Celsius(0.0)),
+///     (1.0, Temperature::Celsius(25.0)),
+///     (2.0, Temperature::Celsius(100.0)),
+/// ]);
+///
+/// assert_approx_eq!(table.interpolate(1.5).into_inner(), 62.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LookupTable<const N: usize> {
+    entries: [(Float, Temperature); N],
+}
+
+impl<const N: usize> LookupTable<N> {
+    /// Creates a new [LookupTable] from `(voltage, temperature)` entries.
+    ///
+    /// The entries must be sorted by voltage!
+    pub const fn new(entries: [(Float, Temperature); N]) -> LookupTable<N> {
+        LookupTable { entries }
+    }
+
+    /// Returns the table's entries.
+    pub fn entries(&self) -> &[(Float, Temperature); N] {
+        &self.entries
+    }
+
+    /// Finds the temperature for a given voltage.
+    ///
+    /// Between two entries, the temperature is linearly interpolated and
+    /// returned in the unit of the lower entry. Voltages outside the table are
+    /// clamped to the first or last entry.
+    ///
+    /// An empty table (or a `NaN` voltage) gives back a `NaN` temperature.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{calibration::LookupTable, Temperature};
+    /// #
+    /// let table = LookupTable::new([
+    ///     (0.0, Temperature::Kelvin(273.15)),
+    ///     (5.0, Temperature::Kelvin(373.15)),
+    /// ]);
+    ///
+    /// // way off the end of the table
+    /// assert_eq!(table.interpolate(12.0), Temperature::Kelvin(373.15));
+    /// ```
+    pub fn interpolate(&self, voltage: Float) -> Temperature {
+        let (Some(first), Some(last)) = (self.entries.first(), self.entries.last()) else {
+            return Temperature::Kelvin(Float::NAN);
+        };
+
+        // clamp at the ends
+        if voltage <= first.0 {
+            return first.1;
+        }
+
+        if voltage >= last.0 {
+            return last.1;
+        }
+
+        for pair in self.entries.windows(2) {
+            let [(lo_v, lo_t), (hi_v, hi_t)] = pair else {
+                continue;
+            };
+
+            if voltage <= *hi_v {
+                let span = hi_v - lo_v;
+
+                // two entries share a voltage
+                if span == 0.0 {
+                    return *hi_t;
+                }
+
+                let unit = lo_t.unit();
+                let lo = lo_t.get_inner();
+                let hi = hi_t.to_unit(unit).get_inner();
+
+                return Temperature::from((lo + (hi - lo) * ((voltage - lo_v) / span), unit));
+            }
+        }
+
+        // only `NaN` voltages make it here
+        Temperature::from((Float::NAN, first.1.unit()))
+    }
+}
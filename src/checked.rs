@@ -37,9 +37,11 @@
 //!
 //! ```
 
+use core::ops::RangeInclusive;
+
 use onlyerror::{self, Error};
 
-use crate::{Float, Temperature};
+use crate::{Float, Temperature, Unit};
 
 /// A set of bounds for which a [CheckedTemperature] cannot exceed.
 /// By default, these are \[Float::NEG_INFINITY, Float::INFINITY\], but users can change them
@@ -87,12 +89,30 @@ impl Bounds {
         return f64::MIN;
     }
 
+    /// Checks if a lower bound is so low that it can't mean anything but "no
+    /// bound".
+    ///
+    /// That's anything at or below half of `Float::MIN`, negative infinity
+    /// included. No real temperature gets anywhere close, and converting a
+    /// value that big between units could overflow to infinity.
+    const fn lower_is_unbounded(bound: Float) -> bool {
+        bound <= Bounds::get_float_min() / 2.0
+    }
+
+    /// Checks if an upper bound is so high that it can't mean anything but
+    /// "no bound". That's anything at or above half of `Float::MAX`,
+    /// infinity included.
+    const fn upper_is_unbounded(bound: Float) -> bool {
+        bound >= Bounds::get_float_max() / 2.0
+    }
+
     /// Tries to set the lower bound to a given value.
     /// Can fail if larger than the Float's `MAX` or the upper bound.
+    /// Negative infinity is fine: it removes the lower bound.
     pub fn set_lower(&mut self, val: Float) -> Result<(), CheckedTempError> {
         if val > self.upper {
             return Err(CheckedTempError::BoundTooHigh(val));
-        } else if val < Bounds::get_float_min() {
+        } else if val < Bounds::get_float_min() && val != Float::NEG_INFINITY {
             return Err(CheckedTempError::BoundTooLow(val));
         }
 
@@ -103,10 +123,11 @@ impl Bounds {
 
     /// Tries to set the upper bound to some given value.
     /// Fails when the value is under `Float::MIN` or the lower bound.
+    /// Infinity is fine: it removes the upper bound.
     pub fn set_upper(&mut self, val: Float) -> Result<(), CheckedTempError> {
         if val < self.lower {
             return Err(CheckedTempError::BoundTooLow(val));
-        } else if val > Bounds::get_float_max() {
+        } else if val > Bounds::get_float_max() && val != Float::INFINITY {
             return Err(CheckedTempError::BoundTooHigh(val));
         }
 
@@ -131,6 +152,12 @@ pub enum CheckedTempError {
     DivisionByZero,
     #[error("NaN values are not allowed for CheckedTemperature construction.")]
     GivenValueIsNan,
+    #[error("The given temperature, {0}, was infinite.")]
+    GivenValueIsInfinite(Float),
+    #[error("Value {value} was out of bounds converting {from} → {to}.")]
+    ConversionOutOfBounds { value: Float, from: Unit, to: Unit },
+    #[error("Can't scale a temperature by a negative factor, {0}.")]
+    NegativeScale(Float),
 }
 
 /// A [Temperature] that cannot be invalid.
@@ -169,8 +196,23 @@ impl CheckedTemperature {
             return Err(CheckedTempError::GivenValueIsNan);
         }
 
+        // negative infinity is already below absolute zero
+        if temp.get_inner().is_infinite() {
+            return Err(CheckedTempError::GivenValueIsInfinite(temp.get_inner()));
+        }
+
+        self.check_bounds(temp)
+    }
+
+    /// Checks a temperature against just the user-set bounds.
+    ///
+    /// Bounds that are effectively unbounded are ignored, the same way
+    /// [adjust_bounds](CheckedTemperature::adjust_bounds) leaves them alone.
+    fn check_bounds(&self, temp: Temperature) -> Result<(), CheckedTempError> {
+        let Bounds { lower, upper } = self.bounds;
+
         // over user-set upper bound
-        if temp.get_inner() > self.bounds.upper {
+        if !Bounds::upper_is_unbounded(upper) && temp.get_inner() > upper {
             return Err(CheckedTempError::TempOutOfBounds(
                 temp.get_inner(),
                 "Too high!",
@@ -178,7 +220,7 @@ impl CheckedTemperature {
         }
 
         // under user-set lower bound
-        if temp.get_inner() < self.bounds.lower {
+        if !Bounds::lower_is_unbounded(lower) && temp.get_inner() < lower {
             return Err(CheckedTempError::TempOutOfBounds(
                 temp.get_inner(),
                 "Too low!",
@@ -188,8 +230,25 @@ impl CheckedTemperature {
         Ok(())
     }
 
+    /// Makes sure a lower bound (in the current unit) isn't below absolute
+    /// zero.
+    ///
+    /// Negative infinity is allowed, since it means "no lower bound".
+    fn check_lower_bound(&self, bound: Float) -> Result<(), CheckedTempError> {
+        if bound == Float::NEG_INFINITY {
+            return Ok(());
+        }
+
+        if Temperature::from((bound, self.temp.unit())).is_below_abs_zero() {
+            return Err(CheckedTempError::BelowAbsoluteZero(bound));
+        }
+
+        Ok(())
+    }
+
     /// Tries to create a new [CheckedTemperature] from a given [Temperature].
-    /// Fails if temperature is invalid (below absolute zero).
+    /// Fails if temperature is invalid (below absolute zero, `NaN`, or
+    /// infinite).
     ///
     /// # Usage
     ///
@@ -204,34 +263,87 @@ impl CheckedTemperature {
     /// # }
     /// ```
     pub fn new(temp: Temperature) -> Result<CheckedTemperature, CheckedTempError> {
-        if temp.is_below_abs_zero() {
-            return Err(CheckedTempError::BelowAbsoluteZero(temp.get_inner()));
-        }
+        let new = CheckedTemperature {
+            temp,
+            bounds: Bounds::default(),
+        };
 
-        if temp.is_nan() {
-            return Err(CheckedTempError::GivenValueIsNan);
-        }
+        new.check(temp)?;
+        Ok(new)
+    }
 
-        // over upper bound
-        if temp.get_inner() > Bounds::get_float_max() {
-            return Err(CheckedTempError::TempOutOfBounds(
-                temp.get_inner(),
-                "Too high!",
-            ));
-        }
+    /// Tries to create a new [CheckedTemperature] that must stay within the
+    /// given bounds.
+    ///
+    /// Fails if the bounds are invalid, or if the temperature is invalid or
+    /// outside of them.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let thermostat = CheckedTemperature::with_bounds(Temperature::Fahrenheit(70.0), 68.0, 72.0)?;
+    ///
+    ///     // too cold!
+    ///     assert!(CheckedTemperature::with_bounds(Temperature::Fahrenheit(60.0), 68.0, 72.0).is_err());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn with_bounds(
+        temp: Temperature,
+        lower_bound: Float,
+        upper_bound: Float,
+    ) -> Result<CheckedTemperature, CheckedTempError> {
+        let mut new = CheckedTemperature::new(temp)?;
+        new.set_bounds(lower_bound, upper_bound)?;
 
-        // under lower bound
-        if temp.get_inner() < Bounds::get_float_min() {
-            return Err(CheckedTempError::TempOutOfBounds(
-                temp.get_inner(),
-                "Too low!",
-            ));
-        }
+        new.check(temp)?;
+        Ok(new)
+    }
 
-        Ok(CheckedTemperature {
-            temp,
-            bounds: Bounds::default(),
-        })
+    /// Like [CheckedTemperature::new], but forgives a little floating point
+    /// noise around absolute zero.
+    ///
+    /// A value that should be exactly absolute zero can come out of some
+    /// math a hair below it. If `temp` is below absolute zero by no more than
+    /// `abs_zero_epsilon` (in `temp`'s unit), it's snapped to exactly
+    /// absolute zero instead of being rejected. Anything further below still
+    /// fails with [CheckedTempError::BelowAbsoluteZero].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let noisy = Temperature::Kelvin(-1e-9);
+    /// assert!(CheckedTemperature::new(noisy).is_err());
+    ///
+    /// let snapped = CheckedTemperature::new_with_tolerance(noisy, 1e-6)?;
+    /// assert_eq!(snapped.into_unchecked(), Temperature::Kelvin(0.0));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_tolerance(
+        temp: Temperature,
+        abs_zero_epsilon: Float,
+    ) -> Result<CheckedTemperature, CheckedTempError> {
+        let abs_zero = temp.absolute_zero();
+
+        let temp = if temp.is_below_abs_zero()
+            && abs_zero.get_inner() - temp.get_inner() <= abs_zero_epsilon
+        {
+            abs_zero
+        } else {
+            temp
+        };
+
+        CheckedTemperature::new(temp)
     }
 
     /// Tries to change the current value of `Self` to a new [Temperature].
@@ -258,6 +370,61 @@ impl CheckedTemperature {
         Ok(())
     }
 
+    /// Like [CheckedTemperature::set_temperature], but hands back the
+    /// temperature it replaced, just like `core::mem::replace`.
+    ///
+    /// If `new` doesn't pass the checks, nothing changes and you get the
+    /// error instead.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut setpoint = CheckedTemperature::new(Temperature::Celsius(21.0))?;
+    ///
+    /// let undo = setpoint.replace_temperature(Temperature::Celsius(23.0))?;
+    /// assert_eq!(undo, Temperature::Celsius(21.0));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn replace_temperature(
+        &mut self,
+        new: Temperature,
+    ) -> Result<Temperature, CheckedTempError> {
+        self.check(new)?;
+
+        Ok(core::mem::replace(&mut self.temp, new))
+    }
+
+    /// Checks whether [CheckedTemperature::set_temperature] would accept
+    /// `candidate`, without changing anything.
+    ///
+    /// It runs the exact same validation: `candidate` can't be below
+    /// absolute zero, `NaN`, infinite, or outside the bounds.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut oven = CheckedTemperature::new(Temperature::Celsius(180.0))?;
+    /// oven.set_bounds(50.0, 250.0)?;
+    ///
+    /// assert!(oven.would_accept(Temperature::Celsius(220.0)));
+    /// assert!(!oven.would_accept(Temperature::Celsius(300.0)));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn would_accept(&self, candidate: Temperature) -> bool {
+        self.check(candidate).is_ok()
+    }
+
     /// Returns the internal unchecked [Temperature].
     ///
     /// # Usage
@@ -343,45 +510,75 @@ impl CheckedTemperature {
     }
 
     /// helper function to adjust the bounds.
-    fn adjust_bounds(
-        &mut self,
-        new_unit: fn(Float) -> Temperature,
-    ) -> Result<(), CheckedTempError> {
-        let current_unit = match self.temp {
-            Temperature::Fahrenheit(_) => Temperature::Fahrenheit,
-            Temperature::Celsius(_) => Temperature::Celsius,
-            Temperature::Kelvin(_) => Temperature::Kelvin,
-        };
+    ///
+    /// Bounds that are effectively unbounded (see
+    /// [Bounds::lower_is_unbounded] and [Bounds::upper_is_unbounded]) are
+    /// left exactly as they are. Converting them could overflow, and they
+    /// mean "no bound" in any unit anyways.
+    fn adjust_bounds(&mut self, new_unit: Unit) {
+        let current_unit = self.temp.unit();
 
         // don't bother converting if we're converting to the same type
         if new_unit == current_unit {
-            return Ok(());
+            return;
         }
 
-        // don't try to convert infinities
-        if self.bounds.lower == Float::NEG_INFINITY && self.bounds.upper == Float::INFINITY {
-            return Ok(());
+        let convert_bound = |b: Float| -> Float {
+            Temperature::from((b, current_unit))
+                .to_unit(new_unit)
+                .into_inner()
+        };
+
+        if !Bounds::lower_is_unbounded(self.bounds.lower) {
+            self.bounds.lower = convert_bound(self.bounds.lower);
         }
 
-        let set_with_bounds = |b: Float| -> Result<Float, CheckedTempError> {
-            let current_bound = current_unit(b);
+        if !Bounds::upper_is_unbounded(self.bounds.upper) {
+            self.bounds.upper = convert_bound(self.bounds.upper);
+        }
+    }
 
-            Ok(match new_unit(0.0) {
-                Temperature::Fahrenheit(_) => current_bound.to_fahrenheit().into_inner(),
-                Temperature::Celsius(_) => current_bound.to_celsius().into_inner(),
-                Temperature::Kelvin(_) => current_bound.to_kelvin().into_inner(),
-            })
-        };
+    /// helper function to convert `Self` into another unit, adjusting the
+    /// bounds and checking the result.
+    fn convert(&self, new_unit: Unit) -> Result<CheckedTemperature, CheckedTempError> {
+        let from = self.temp.unit();
 
-        if self.bounds.lower != Float::NEG_INFINITY {
-            self.bounds.lower = set_with_bounds(self.bounds.lower)?;
+        // same unit: nothing to convert, and `self` was already checked
+        if new_unit == from {
+            return Ok(*self);
         }
 
-        if self.bounds.upper != Float::INFINITY {
-            self.bounds.upper = set_with_bounds(self.bounds.upper)?;
+        let mut new = *self;
+
+        new.adjust_bounds(new_unit);
+
+        new.temp = match new_unit {
+            Unit::Fahrenheit => new.temp.to_fahrenheit(),
+            Unit::Celsius => new.temp.to_celsius(),
+            Unit::Kelvin => new.temp.to_kelvin(),
+        };
+
+        // `self` was already checked, so only the bounds need another look.
+        // rechecking absolute zero would trip over float drift, like -459.67 F
+        // landing a hair below -273.15 C. say which units were involved if the
+        // bounds are what broke
+        new.check_bounds(new.temp).map_err(|e| match e {
+            CheckedTempError::TempOutOfBounds(value, _) => {
+                CheckedTempError::ConversionOutOfBounds {
+                    value,
+                    from,
+                    to: new_unit,
+                }
+            }
+            other => other,
+        })?;
+
+        // a huge value can still overflow on its way into a bigger unit
+        if new.temp.get_inner().is_infinite() {
+            return Err(CheckedTempError::GivenValueIsInfinite(new.temp.get_inner()));
         }
 
-        Ok(())
+        Ok(new)
     }
 
     /// Converts the internal [Temperature] to Fahrenheit and rewraps it.
@@ -404,13 +601,7 @@ impl CheckedTemperature {
     /// # }
     /// ```
     pub fn to_fahrenheit(&self) -> Result<CheckedTemperature, CheckedTempError> {
-        let mut new = *self;
-
-        // adjust bounds
-        new.adjust_bounds(Temperature::Fahrenheit)?;
-
-        new.temp = new.temp.to_fahrenheit();
-        Ok(new)
+        self.convert(Unit::Fahrenheit)
     }
 
     /// Converts the internal [Temperature] to Celsius and rewraps it.
@@ -434,11 +625,8 @@ impl CheckedTemperature {
     /// # }
     /// ```
     pub fn to_celsius(&mut self) -> Result<CheckedTemperature, CheckedTempError> {
-        // adjust bounds
-        self.adjust_bounds(Temperature::Celsius)?;
-
-        self.temp = self.temp.to_celsius();
-        Ok(self.to_owned())
+        *self = self.convert(Unit::Celsius)?;
+        Ok(*self)
     }
 
     /// Converts the internal [Temperature] to Kelvin and rewraps it.
@@ -461,11 +649,8 @@ impl CheckedTemperature {
     /// # }
     /// ```
     pub fn to_kelvin(&mut self) -> Result<CheckedTemperature, CheckedTempError> {
-        // adjust bounds
-        self.adjust_bounds(Temperature::Kelvin)?;
-
-        self.temp = self.temp.to_kelvin();
-        Ok(self.to_owned())
+        *self = self.convert(Unit::Kelvin)?;
+        Ok(*self)
     }
 
     // a little math...
@@ -596,8 +781,151 @@ impl CheckedTemperature {
         Ok(())
     }
 
+    /// Tries to scale the temperature by some factor, in Kelvin.
+    ///
+    /// Unlike [CheckedTemperature::mul], which multiplies the raw number in
+    /// whatever unit it's in, this scales the *physical* temperature. So,
+    /// doubling 0.0° C gives you 273.15° C, not 0.0° C. The result is
+    /// converted back into the current unit and checked against the bounds.
+    ///
+    /// Negative factors can never make a valid temperature, so they're
+    /// rejected with [CheckedTempError::NegativeScale].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let ice = CheckedTemperature::new(Temperature::Celsius(0.0))?;
+    ///     let doubled = ice.scale_kelvin(2.0)?;
+    ///
+    ///     assert_approx_eq!(doubled.get_inner(), 273.15);
+    ///     assert!(ice.scale_kelvin(-1.0).is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn scale_kelvin(self, factor: Float) -> Result<CheckedTemperature, CheckedTempError> {
+        if factor < 0.0 {
+            return Err(CheckedTempError::NegativeScale(factor));
+        }
+
+        let unit = self.temp.unit();
+        let scaled = Temperature::Kelvin(self.temp.to_kelvin().into_inner() * factor).to_unit(unit);
+        self.check(scaled)?;
+
+        Ok(CheckedTemperature {
+            temp: scaled,
+            ..self
+        })
+    }
+
+    // ...and here are versions that hand back the result, so you can chain
+    // them together with `?`
+
+    /// Like [CheckedTemperature::add], but returns the result instead of
+    /// changing `self`.
+    ///
+    /// These `try_*` methods are handy for chaining conversions and math
+    /// together in a single expression.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let room = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    ///     let warmer = room.to_fahrenheit()?.try_add(Temperature::Fahrenheit(2.0))?;
+    ///
+    ///     assert_approx_eq!(warmer.get_inner(), 70.0);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn try_add(mut self, temp: Temperature) -> Result<CheckedTemperature, CheckedTempError> {
+        self.add(temp)?;
+        Ok(self)
+    }
+
+    /// Like [CheckedTemperature::sub], but returns the result instead of
+    /// changing `self`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let my_temp = CheckedTemperature::new(Temperature::Celsius(32.0))?;
+    ///     assert_approx_eq!(my_temp.try_sub(Temperature::Celsius(2.0))?.get_inner(), 30.0);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn try_sub(mut self, temp: Temperature) -> Result<CheckedTemperature, CheckedTempError> {
+        self.sub(temp)?;
+        Ok(self)
+    }
+
+    /// Like [CheckedTemperature::mul], but returns the result instead of
+    /// changing `self`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let my_temp = CheckedTemperature::new(Temperature::Celsius(32.0))?;
+    ///     assert_approx_eq!(my_temp.try_mul(2.0)?.get_inner(), 64.0);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn try_mul(mut self, num: Float) -> Result<CheckedTemperature, CheckedTempError> {
+        self.mul(num)?;
+        Ok(self)
+    }
+
+    /// Like [CheckedTemperature::div], but returns the result instead of
+    /// changing `self`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{checked::CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    ///     let my_temp = CheckedTemperature::new(Temperature::Celsius(32.0))?;
+    ///     assert_approx_eq!(my_temp.try_div(2.0)?.get_inner(), 16.0);
+    ///     assert!(my_temp.try_div(0.0).is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn try_div(mut self, num: Float) -> Result<CheckedTemperature, CheckedTempError> {
+        self.div(num)?;
+        Ok(self)
+    }
+
     /// Tries to set the upper allowed bound to a given value.
     ///
+    /// `Float::INFINITY` removes the upper bound again. So does anything at or
+    /// above half of `Float::MAX`: no temperature can get that high anyways,
+    /// and it's left as-is when converting between units.
+    ///
     /// # Usage
     ///
     #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
@@ -621,6 +949,11 @@ impl CheckedTemperature {
 
     /// Tries to set the lower allowed bound to a given value.
     ///
+    /// The bound is in the current unit, and it can't be below absolute zero.
+    /// (Nothing could ever reach a bound like that!) You'll get a
+    /// [CheckedTempError::BelowAbsoluteZero] if you try. The one exception
+    /// is `Float::NEG_INFINITY`, which removes the lower bound again.
+    ///
     /// # Usage
     ///
     #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
@@ -638,12 +971,16 @@ impl CheckedTemperature {
     ///
     /// ```
     pub fn set_lower_bound(&mut self, bound: Float) -> Result<(), CheckedTempError> {
+        self.check_lower_bound(bound)?;
         self.bounds.set_lower(bound)?;
         Ok(())
     }
 
     /// Tries to set both bounds to the given values.
     ///
+    /// Like [CheckedTemperature::set_lower_bound], the lower bound can't be
+    /// below absolute zero.
+    ///
     /// # Usage
     ///
     #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
@@ -665,12 +1002,238 @@ impl CheckedTemperature {
         lower_bound: Float,
         upper_bound: Float,
     ) -> Result<(), CheckedTempError> {
+        self.check_lower_bound(lower_bound)?;
         self.bounds.set_lower(lower_bound)?;
         self.bounds.set_upper(upper_bound)?;
 
         Ok(())
     }
 
+    /// Tries to set both bounds from a `lower..=upper` range, in the current
+    /// unit.
+    ///
+    /// Unlike calling [CheckedTemperature::set_bounds], the range is checked
+    /// as a whole before anything changes. An inverted range (`lower` above
+    /// `upper`) fails with [CheckedTempError::BoundTooHigh], and a `NaN`
+    /// endpoint fails with [CheckedTempError::GivenValueIsNan]. The lower
+    /// bound can't be below absolute zero, either.
+    ///
+    /// If this fails, the old bounds are left alone.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut thermostat = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    /// thermostat.set_range(68.0..=72.0)?;
+    ///
+    /// assert!(thermostat.set_temperature(Temperature::Fahrenheit(65.0)).is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_range(&mut self, range: RangeInclusive<Float>) -> Result<(), CheckedTempError> {
+        let (lower, upper) = range.into_inner();
+
+        if lower.is_nan() || upper.is_nan() {
+            return Err(CheckedTempError::GivenValueIsNan);
+        }
+
+        if lower > upper {
+            return Err(CheckedTempError::BoundTooHigh(lower));
+        }
+
+        self.check_lower_bound(lower)?;
+
+        let mut bounds = Bounds::default();
+        bounds.set_lower(lower)?;
+        bounds.set_upper(upper)?;
+        self.bounds = bounds;
+
+        Ok(())
+    }
+
+    /// Tries to set both bounds from a range of [Temperature]s, in any unit.
+    ///
+    /// Both ends are converted to the current unit first, then this works
+    /// just like [CheckedTemperature::set_range].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut fridge = CheckedTemperature::new(Temperature::Fahrenheit(38.0))?;
+    /// fridge.set_range_temp(Temperature::Celsius(0.0)..=Temperature::Celsius(5.0))?;
+    ///
+    /// let (lower, upper) = fridge.get_bounds();
+    /// assert_approx_eq!(lower.into_inner(), 32.0);
+    /// assert_approx_eq!(upper.into_inner(), 41.0);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_range_temp(
+        &mut self,
+        range: RangeInclusive<Temperature>,
+    ) -> Result<(), CheckedTempError> {
+        let (lower, upper) = range.into_inner();
+        let unit = self.temp.unit();
+
+        self.set_range(lower.to_unit(unit).into_inner()..=upper.to_unit(unit).into_inner())
+    }
+
+    /// Returns an iterator that sweeps from the lower bound up to the upper
+    /// bound, `step` at a time, in the current unit.
+    ///
+    /// Every item carries the same bounds as `self`. The upper bound is
+    /// included when a step lands right on it, but floating point error can
+    /// leave it just out of reach (like with a `step` of `0.1`).
+    ///
+    /// The bounds need to be finite for this to make sense, and `step` has
+    /// to be positive. If they aren't, the iterator is empty.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut oven = CheckedTemperature::new(Temperature::Celsius(180.0))?;
+    /// oven.set_bounds(150.0, 250.0)?;
+    ///
+    /// let sweep: Vec<_> = oven.iter_range(50.0).map(|t| t.into_inner()).collect();
+    /// assert_eq!(sweep, [150.0, 200.0, 250.0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_range(&self, step: Float) -> impl Iterator<Item = CheckedTemperature> {
+        let Bounds { lower, upper } = self.bounds;
+        let unit = self.temp.unit();
+        let bounds = self.bounds;
+
+        let usable = lower.is_finite() && upper.is_finite() && step > 0.0;
+        let limit = if usable { usize::MAX } else { 0 };
+
+        // multiplying (instead of adding up steps) keeps error from piling up
+        (0..limit)
+            .map(move |i| lower + i as Float * step)
+            .take_while(move |value| *value <= upper)
+            .map(move |value| CheckedTemperature {
+                temp: Temperature::from((value, unit)),
+                bounds,
+            })
+            .filter(|t| t.check(t.temp).is_ok())
+    }
+
+    /// Checks that the bounds make sense: `lower <= upper`, and neither one
+    /// is below absolute zero (in the current unit).
+    ///
+    /// Setting bounds always checks this, but floating point drift from
+    /// converting between units can push a bound set right at absolute zero
+    /// just past it. (That's most likely with the `f32` feature.) Unbounded
+    /// sides, `-inf` and `inf`, are always fine. A `NaN` bound never is.
+    ///
+    /// If this returns `false`, [CheckedTemperature::repair_bounds] can fix
+    /// it.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut temp = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    /// temp.set_bounds(-459.67, 100.0)?;
+    ///
+    /// let temp = temp.to_kelvin()?;
+    /// if !temp.bounds_valid() {
+    ///     println!("drifted! {:?}", temp.get_bounds());
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bounds_valid(&self) -> bool {
+        let Bounds { lower, upper } = self.bounds;
+        let unit = self.temp.unit();
+
+        let lower_ok = lower == Float::NEG_INFINITY
+            || (!lower.is_nan() && !Temperature::from((lower, unit)).is_below_abs_zero());
+        let upper_ok = upper == Float::INFINITY
+            || (!upper.is_nan() && !Temperature::from((upper, unit)).is_below_abs_zero());
+
+        lower_ok && upper_ok && lower <= upper
+    }
+
+    /// Fixes up the bounds so that [CheckedTemperature::bounds_valid] is
+    /// `true`.
+    ///
+    /// A `NaN` bound is reset to unbounded, bounds in the wrong order are
+    /// swapped, and any finite bound below absolute zero is raised to
+    /// absolute zero. Valid bounds aren't touched.
+    ///
+    /// This doesn't look at the current temperature, which might end up
+    /// outside the repaired bounds.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(not(feature = "checked"), doc = "```ignore")]
+    #[cfg_attr(feature = "checked", doc = "```")]
+    /// # use simmer::{CheckedTemperature, Temperature};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut temp = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    /// temp.set_bounds(-459.67, 100.0)?;
+    ///
+    /// let mut temp = temp.to_kelvin()?;
+    /// temp.repair_bounds();
+    ///
+    /// assert!(temp.bounds_valid());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn repair_bounds(&mut self) {
+        let Bounds {
+            mut lower,
+            mut upper,
+        } = self.bounds;
+
+        if lower.is_nan() {
+            lower = Float::NEG_INFINITY;
+        }
+
+        if upper.is_nan() {
+            upper = Float::INFINITY;
+        }
+
+        if lower > upper {
+            core::mem::swap(&mut lower, &mut upper);
+        }
+
+        let abs_zero = self.temp.absolute_zero().into_inner();
+
+        if lower.is_finite() && lower < abs_zero {
+            lower = abs_zero;
+        }
+
+        if upper.is_finite() && upper < abs_zero {
+            upper = abs_zero;
+        }
+
+        self.bounds = Bounds { lower, upper };
+    }
+
     /// Returns the bounds of this `CheckedTemperature` as (unchecked)
     /// [Temperature]s.
     ///
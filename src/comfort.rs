@@ -0,0 +1,133 @@
+//! # Comfort
+//!
+//! Buckets indoor temperatures into how they'd feel to a person.
+//!
+//! This is mostly for HVAC dashboards and the like. The default thresholds
+//! are in [ComfortThresholds], but you can bring your own with
+//! [Temperature::comfort_zone_with].
+
+use crate::Temperature;
+
+/// How an indoor temperature feels, from coldest to hottest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComfortZone {
+    Cold,
+    Cool,
+    Comfortable,
+    Warm,
+    Hot,
+}
+
+/// The temperatures where each [ComfortZone] begins.
+///
+/// Each threshold is the *lowest* temperature in its zone, so a temperature
+/// sitting exactly on a threshold belongs to the warmer zone. Anything below
+/// `cool` is [ComfortZone::Cold].
+///
+/// The defaults are:
+///
+/// | Zone          | Celsius         |
+/// | ------------- | --------------- |
+/// | `Cold`        | below 16°       |
+/// | `Cool`        | 16° up to 20°   |
+/// | `Comfortable` | 20° up to 24°   |
+/// | `Warm`        | 24° up to 28°   |
+/// | `Hot`         | 28° and above   |
+///
+/// Thresholds can be in any unit. They're compared in Kelvin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComfortThresholds {
+    pub cool: Temperature,
+    pub comfortable: Temperature,
+    pub warm: Temperature,
+    pub hot: Temperature,
+}
+
+impl ComfortThresholds {
+    /// Creates a new set of [ComfortThresholds].
+    ///
+    /// They should be given from coldest to hottest.
+    pub const fn new(
+        cool: Temperature,
+        comfortable: Temperature,
+        warm: Temperature,
+        hot: Temperature,
+    ) -> ComfortThresholds {
+        ComfortThresholds {
+            cool,
+            comfortable,
+            warm,
+            hot,
+        }
+    }
+}
+
+impl Default for ComfortThresholds {
+    /// The thresholds listed on [ComfortThresholds].
+    fn default() -> Self {
+        ComfortThresholds::new(
+            Temperature::Celsius(16.0),
+            Temperature::Celsius(20.0),
+            Temperature::Celsius(24.0),
+            Temperature::Celsius(28.0),
+        )
+    }
+}
+
+impl Temperature {
+    /// Buckets `self` into a [ComfortZone] using the default
+    /// [ComfortThresholds].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{comfort::ComfortZone, Temperature};
+    /// #
+    /// assert_eq!(Temperature::Celsius(22.0).comfort_zone(), ComfortZone::Comfortable);
+    /// assert_eq!(Temperature::Fahrenheit(90.0).comfort_zone(), ComfortZone::Hot);
+    /// ```
+    pub fn comfort_zone(&self) -> ComfortZone {
+        self.comfort_zone_with(&ComfortThresholds::default())
+    }
+
+    /// Buckets `self` into a [ComfortZone] using your own
+    /// [ComfortThresholds].
+    ///
+    /// A `NaN` temperature doesn't reach any threshold, so it's
+    /// [ComfortZone::Cold].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{comfort::{ComfortThresholds, ComfortZone}, Temperature};
+    /// #
+    /// // i run warm!
+    /// let mine = ComfortThresholds::new(
+    ///     Temperature::Fahrenheit(55.0),
+    ///     Temperature::Fahrenheit(62.0),
+    ///     Temperature::Fahrenheit(70.0),
+    ///     Temperature::Fahrenheit(78.0),
+    /// );
+    ///
+    /// let office = Temperature::Fahrenheit(72.0);
+    /// assert_eq!(office.comfort_zone_with(&mine), ComfortZone::Warm);
+    /// ```
+    pub fn comfort_zone_with(&self, thresholds: &ComfortThresholds) -> ComfortZone {
+        let k = self.to_kelvin().into_inner();
+        let reaches = |threshold: Temperature| k >= threshold.to_kelvin().into_inner();
+
+        if reaches(thresholds.hot) {
+            ComfortZone::Hot
+        } else if reaches(thresholds.warm) {
+            ComfortZone::Warm
+        } else if reaches(thresholds.comfortable) {
+            ComfortZone::Comfortable
+        } else if reaches(thresholds.cool) {
+            ComfortZone::Cool
+        } else {
+            ComfortZone::Cold
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! # Convert
+//!
+//! Lower-level conversion helpers that work on raw floating point numbers.
+//!
+//! Every conversion between our units is linear, so it can be written as
+//! `to_value = from_value * scale + offset`. When you're converting a big
+//! batch of numbers from one fixed unit to another, you can grab those
+//! coefficients once with [linear_coeffs] and reuse them with [apply_coeffs].
+//!
+//! If you'd rather not wrap your numbers in a [Temperature](crate::Temperature)
+//! at all, there's a direct function for each pair of units, too. (Like
+//! [fahrenheit_to_kelvin].)
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::{convert, Unit};
+//! # use assert_approx_eq::assert_approx_eq;
+//!
+//! let coeffs = convert::linear_coeffs(Unit::Celsius, Unit::Fahrenheit);
+//!
+//! let readings_c = [0.0, 37.0, 100.0];
+//! let readings_f = readings_c.map(|c| convert::apply_coeffs(c, coeffs));
+//!
+//! assert_approx_eq!(readings_f[2], 212.0);
+//! ```
+
+use crate::{Float, Temperature, Unit};
+
+/// Returns the `(scale, offset)` coefficients for converting a value from
+/// one [Unit] to another.
+///
+/// The conversion is `to_value = from_value * scale + offset`.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{convert::linear_coeffs, Unit};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let (scale, offset) = linear_coeffs(Unit::Celsius, Unit::Kelvin);
+///
+/// assert_approx_eq!(scale, 1.0);
+/// assert_approx_eq!(offset, 273.15);
+/// ```
+pub const fn linear_coeffs(from: Unit, to: Unit) -> (Float, Float) {
+    match (from, to) {
+        (Unit::Fahrenheit, Unit::Fahrenheit)
+        | (Unit::Celsius, Unit::Celsius)
+        | (Unit::Kelvin, Unit::Kelvin) => (1.0, 0.0),
+
+        (Unit::Celsius, Unit::Fahrenheit) => (1.8, 32.0),
+        (Unit::Fahrenheit, Unit::Celsius) => (1.0 / 1.8, -32.0 / 1.8),
+
+        (Unit::Celsius, Unit::Kelvin) => (1.0, 273.15),
+        (Unit::Kelvin, Unit::Celsius) => (1.0, -273.15),
+
+        (Unit::Kelvin, Unit::Fahrenheit) => (1.8, 32.0 - (273.15 * 1.8)),
+        (Unit::Fahrenheit, Unit::Kelvin) => (1.0 / 1.8, 273.15 - (32.0 / 1.8)),
+    }
+}
+
+/// Applies `(scale, offset)` coefficients from [linear_coeffs] to a value.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{convert::{apply_coeffs, linear_coeffs}, Unit};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let coeffs = linear_coeffs(Unit::Fahrenheit, Unit::Celsius);
+/// assert_approx_eq!(apply_coeffs(98.6, coeffs), 37.0);
+/// ```
+pub const fn apply_coeffs(value: Float, coeffs: (Float, Float)) -> Float {
+    value * coeffs.0 + coeffs.1
+}
+
+/// Converts a Fahrenheit value directly into Celsius.
+///
+/// `c = (f - 32) / 1.8`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::fahrenheit_to_celsius;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(fahrenheit_to_celsius(212.0), 100.0);
+/// ```
+pub const fn fahrenheit_to_celsius(f: Float) -> Float {
+    (f - 32.0) / 1.8
+}
+
+/// Converts a Fahrenheit value directly into Kelvin.
+///
+/// `k = (f + 459.67) / 1.8`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::fahrenheit_to_kelvin;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(fahrenheit_to_kelvin(32.0), 273.15);
+/// ```
+pub const fn fahrenheit_to_kelvin(f: Float) -> Float {
+    (f + 459.67) / 1.8
+}
+
+/// Converts a Celsius value directly into Fahrenheit.
+///
+/// `f = c * 1.8 + 32`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::celsius_to_fahrenheit;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(celsius_to_fahrenheit(37.0), 98.6);
+/// ```
+pub const fn celsius_to_fahrenheit(c: Float) -> Float {
+    (c * 1.8) + 32.0
+}
+
+/// Converts a Celsius value directly into Kelvin.
+///
+/// `k = c + 273.15`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::celsius_to_kelvin;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(celsius_to_kelvin(-273.15), 0.0);
+/// ```
+pub const fn celsius_to_kelvin(c: Float) -> Float {
+    c + 273.15
+}
+
+/// Converts a Kelvin value directly into Fahrenheit.
+///
+/// `f = k * 1.8 - 459.67`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::kelvin_to_fahrenheit;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(kelvin_to_fahrenheit(0.0), -459.67);
+/// ```
+pub const fn kelvin_to_fahrenheit(k: Float) -> Float {
+    (k * 1.8) - 459.67
+}
+
+/// Converts a Kelvin value directly into Celsius.
+///
+/// `c = k - 273.15`
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::convert::kelvin_to_celsius;
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// assert_approx_eq!(kelvin_to_celsius(373.15), 100.0);
+/// ```
+pub const fn kelvin_to_celsius(k: Float) -> Float {
+    k - 273.15
+}
+
+/// Converts a whole slice of raw values from one [Unit] to another, writing
+/// the results into `out`.
+///
+/// The coefficients are computed once up front, so this is just a
+/// multiply-add per element. The compiler can usually vectorize it, which
+/// makes this the fast path for converting big batches of readings.
+///
+/// # Panics
+///
+/// `out.len()` must equal `values.len()`. If it doesn't, this panics.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{convert::convert_raw_slice, Unit};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let readings_c = [0.0, 37.0, 100.0];
+/// let mut readings_f = [0.0; 3];
+///
+/// convert_raw_slice(&readings_c, Unit::Celsius, Unit::Fahrenheit, &mut readings_f);
+/// assert_approx_eq!(readings_f[1], 98.6);
+/// ```
+pub fn convert_raw_slice(values: &[Float], from: Unit, to: Unit, out: &mut [Float]) {
+    assert_eq!(
+        values.len(),
+        out.len(),
+        "`values` and `out` must be the same length"
+    );
+
+    let (scale, offset) = linear_coeffs(from, to);
+
+    for (value, out) in values.iter().zip(out.iter_mut()) {
+        *out = value * scale + offset;
+    }
+}
+
+/// Converts a whole array of raw `values` from one [Unit] to another,
+/// wrapping each one up as a [Temperature].
+///
+/// This is a `const fn`, so you can use it to build lookup tables at
+/// compile time. (Great for ROM, since nothing has to run on boot!) Like
+/// [convert_raw_slice], it's one multiply-add per element with the
+/// coefficients from [linear_coeffs].
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{convert::convert_array, Temperature, Unit};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// const TABLE_F: [Temperature; 3] =
+///     convert_array([0.0, 37.0, 100.0], Unit::Celsius, Unit::Fahrenheit);
+///
+/// assert_approx_eq!(TABLE_F[2].into_inner(), 212.0);
+/// ```
+pub const fn convert_array<const N: usize>(
+    values: [Float; N],
+    from: Unit,
+    to: Unit,
+) -> [Temperature; N] {
+    let coeffs = linear_coeffs(from, to);
+    let mut out = [Temperature::Kelvin(0.0); N];
+
+    // no `for` loops in `const fn` yet
+    let mut i = 0;
+    while i < N {
+        let value = apply_coeffs(values[i], coeffs);
+
+        out[i] = match to {
+            Unit::Fahrenheit => Temperature::Fahrenheit(value),
+            Unit::Celsius => Temperature::Celsius(value),
+            Unit::Kelvin => Temperature::Kelvin(value),
+        };
+
+        i += 1;
+    }
+
+    out
+}
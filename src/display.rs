@@ -0,0 +1,316 @@
+//! # Display
+//!
+//! Extra ways to show a [Temperature] to people.
+//!
+//! The regular `Display` impl just prints the inner number. These helpers
+//! add unit symbols, pick precisions, and so on.
+
+use crate::{Float, Temperature, Unit};
+
+#[cfg(feature = "f32")]
+use ufmt_float::uFmt_f32 as UFmtFloat;
+
+#[cfg(not(feature = "f32"))]
+use ufmt_float::uFmt_f64 as UFmtFloat;
+
+/// Anything colder than this (in Kelvin) is shown in Kelvin by
+/// [Temperature::display_friendly].
+const FRIENDLY_KELVIN_THRESHOLD: Float = 1.0;
+
+/// A [Temperature] formatted with its unit symbol.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Friendly(Temperature);
+
+impl core::fmt::Display for Friendly {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Temperature::Fahrenheit(t) => write!(f, "{t:.1}°F"),
+            Temperature::Celsius(t) => write!(f, "{t:.1}°C"),
+            Temperature::Kelvin(t) => write!(f, "{t:.2} K"),
+        }
+    }
+}
+
+impl Temperature {
+    /// Returns something that displays `self` in the preferred [Unit], along
+    /// with its symbol.
+    ///
+    /// Fahrenheit and Celsius are shown with one decimal place, like `21.5°C`.
+    ///
+    /// Really cold temperatures (below 1 K) don't read well in everyday
+    /// units, so they're shown in Kelvin with two decimal places instead,
+    /// like `0.25 K`. The same goes for anything that would be below absolute
+    /// zero in the preferred unit.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let room = Temperature::Celsius(21.0);
+    /// assert_eq!(room.display_friendly(Unit::Fahrenheit).to_string(), "69.8°F");
+    ///
+    /// let helium = Temperature::Kelvin(0.5);
+    /// assert_eq!(helium.display_friendly(Unit::Celsius).to_string(), "0.50 K");
+    /// ```
+    pub fn display_friendly(&self, prefer: Unit) -> impl core::fmt::Display {
+        let preferred = self.to_unit(prefer);
+
+        if preferred.is_below_abs_zero()
+            || self.to_kelvin().into_inner() < FRIENDLY_KELVIN_THRESHOLD
+        {
+            return Friendly(self.to_kelvin());
+        }
+
+        Friendly(preferred)
+    }
+
+    /// Splits `self` into whole degrees and the fraction left over, in its
+    /// current unit.
+    ///
+    /// The whole part is truncated toward zero, and the fraction keeps the
+    /// sign of the original value. So, `-3.75` splits into `(-3, -0.75)`, and
+    /// adding the parts back together gives you the original value. (That's
+    /// the same as `trunc` and `fract` in `std`.)
+    ///
+    /// Values too big for an `i32` saturate the whole part, so the fraction
+    /// isn't meaningful for them. `NaN` splits into `(0, NaN)`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let (whole, fraction) = Temperature::Celsius(21.25).split_degrees();
+    ///
+    /// assert_eq!(whole, 21);
+    /// assert_approx_eq!(fraction, 0.25);
+    /// ```
+    pub fn split_degrees(&self) -> (i32, Float) {
+        let value = self.get_inner();
+
+        // `as` truncates toward zero (and saturates)
+        let whole = value as i32;
+
+        (whole, value - whole as Float)
+    }
+
+    /// Encodes the whole part of `self` as `N` binary-coded decimal (BCD)
+    /// digits, for seven-segment display drivers.
+    ///
+    /// Each byte holds one digit, `0..=9` (unpacked BCD), with the most
+    /// significant digit first and leading zeros filled in. The value is in
+    /// its current unit, and the fraction is truncated toward zero, just
+    /// like [Temperature::split_degrees].
+    ///
+    /// BCD has no sign, so you get it separately: the `bool` is `true` for
+    /// negative values. (Something like `-0.5` has a whole part of zero,
+    /// so it isn't negative.)
+    ///
+    /// Returns `None` if the whole part needs more than `N` digits, or if
+    /// `self` is `NaN` or infinite.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let (digits, negative) = Temperature::Celsius(-21.7).to_bcd::<3>().unwrap();
+    ///
+    /// assert_eq!(digits, [0, 2, 1]);
+    /// assert!(negative);
+    ///
+    /// // too big for two digits
+    /// assert_eq!(Temperature::Fahrenheit(451.0).to_bcd::<2>(), None);
+    /// ```
+    pub fn to_bcd<const N: usize>(&self) -> Option<([u8; N], bool)> {
+        let value = self.get_inner();
+
+        // past this, `as u64` saturates and the digits would be wrong
+        if !value.is_finite() || value.abs() >= u64::MAX as Float {
+            return None;
+        }
+
+        // `as` truncates toward zero
+        let mut whole = value.abs() as u64;
+        let negative = value < 0.0 && whole != 0;
+
+        let mut digits = [0; N];
+        for digit in digits.iter_mut().rev() {
+            *digit = (whole % 10) as u8;
+            whole /= 10;
+        }
+
+        // anything left over didn't fit
+        if whole != 0 {
+            return None;
+        }
+
+        Some((digits, negative))
+    }
+}
+
+/// A [Temperature] that debug-prints its Kelvin equivalent, too.
+#[derive(Clone, Copy, PartialEq)]
+struct Verbose(Temperature);
+
+impl core::fmt::Debug for Verbose {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} [{:?} K]", self.0, self.0.to_kelvin().into_inner())
+    }
+}
+
+impl Temperature {
+    /// Returns something that debug-prints `self` along with its Kelvin
+    /// equivalent, like `Celsius(25.0) [298.15 K]`.
+    ///
+    /// The derived `Debug` only shows the stored unit, which makes unit
+    /// mixups hard to spot. This leaves that alone and gives you a louder
+    /// version for when you're hunting one down.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let room = Temperature::Celsius(25.0);
+    /// assert_eq!(format!("{:?}", room.debug_verbose()), "Celsius(25.0) [298.15 K]");
+    /// ```
+    pub fn debug_verbose(&self) -> impl core::fmt::Debug {
+        Verbose(*self)
+    }
+}
+
+/// A `ufmt` writer that only counts how many bytes go through it.
+struct ByteCounter(usize);
+
+impl ufmt_write::uWrite for ByteCounter {
+    type Error = core::convert::Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+impl Temperature {
+    /// Writes `self` with `ufmt`, right-aligned in a column `width`
+    /// characters wide.
+    ///
+    /// `ufmt` doesn't support widths on its own, so this measures the number
+    /// first and pads the left side with spaces. Nothing is allocated. If the
+    /// number is already wider than `width`, it's written as-is (never cut
+    /// off).
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// # struct Out(String);
+    /// #
+    /// # impl ufmt_write::uWrite for Out {
+    /// #     type Error = core::convert::Infallible;
+    /// #
+    /// #     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    /// #         self.0.push_str(s);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// struct Column(Temperature);
+    ///
+    /// impl ufmt::uDisplay for Column {
+    ///     fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    ///     where
+    ///         W: ufmt_write::uWrite + ?Sized,
+    ///     {
+    ///         self.0.uwrite_padded(f, 12)
+    ///     }
+    /// }
+    ///
+    /// let mut out = Out(String::new());
+    /// ufmt::uwrite!(out, "|{}|", Column(Temperature::Celsius(21.5))).unwrap();
+    ///
+    /// assert_eq!(out.0, "|    21.50000|");
+    /// ```
+    pub fn uwrite_padded<W>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+        width: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        let mut counter = ByteCounter(0);
+
+        // counting can't fail
+        let _ = ufmt::uwrite!(&mut counter, "{}", *self);
+
+        for _ in counter.0..width {
+            f.write_str(" ")?;
+        }
+
+        ufmt::uwrite!(f, "{}", *self)
+    }
+}
+
+/// A [Temperature] that `ufmt` displays with a chosen number of decimal
+/// places.
+///
+/// The regular `uDisplay` impl always uses five decimal places, which is a
+/// lot for a tiny screen. This one uses the `u8` you give it instead, which
+/// is clamped to `1..=5`.
+///
+/// Like the regular impl, the last digit is truncated, not rounded.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{display::PrecisionTemp, Temperature};
+/// #
+/// # struct Out(String);
+/// #
+/// # impl ufmt_write::uWrite for Out {
+/// #     type Error = core::convert::Infallible;
+/// #
+/// #     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+/// #         self.0.push_str(s);
+/// #         Ok(())
+/// #     }
+/// # }
+/// #
+/// let mut out = Out(String::new());
+/// let temp = PrecisionTemp(Temperature::Celsius(21.5), 1);
+///
+/// ufmt::uwrite!(out, "{}", temp).unwrap();
+/// assert_eq!(out.0, "21.5");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrecisionTemp(pub Temperature, pub u8);
+
+impl ufmt::uDisplay for PrecisionTemp {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        let value = self.0.get_inner();
+
+        let formatted = match self.1.clamp(1, 5) {
+            1 => UFmtFloat::One(value),
+            2 => UFmtFloat::Two(value),
+            3 => UFmtFloat::Three(value),
+            4 => UFmtFloat::Four(value),
+            _ => UFmtFloat::Five(value),
+        };
+
+        ufmt::uwrite!(f, "{}", formatted)
+    }
+}
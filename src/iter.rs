@@ -0,0 +1,430 @@
+//! # Iterators
+//!
+//! Helpers for summarizing a bunch of [Temperature] readings at once.
+//!
+//! Bring [TemperatureIteratorExt] into scope and you can call these right
+//! off of any iterator that yields [Temperature]s - lazy ones included!
+
+use crate::{Float, Temperature};
+
+/// Summarizing methods for any `Iterator<Item = Temperature>`.
+///
+/// All comparisons and averages are done physically, in Kelvin. So, mixing
+/// units in the same iterator is perfectly fine.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// use simmer::{Temperature, TemperatureIteratorExt};
+///
+/// let readings = vec![Temperature::Celsius(20.0), Temperature::Fahrenheit(70.0)];
+///
+/// let hottest = readings.into_iter().max_temperature();
+/// assert_eq!(hottest, Some(Temperature::Fahrenheit(70.0)));
+/// ```
+pub trait TemperatureIteratorExt: Iterator<Item = Temperature> + Sized {
+    /// Averages every reading, returning the mean in Kelvin.
+    ///
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureIteratorExt};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let readings = vec![Temperature::Celsius(0.0), Temperature::Kelvin(283.15)];
+    ///
+    /// let mean = readings.into_iter().mean_temperature().unwrap();
+    /// assert_approx_eq!(mean.to_celsius().into_inner(), 5.0);
+    /// ```
+    fn mean_temperature(self) -> Option<Temperature> {
+        let (sum, count) = self.fold((0.0, 0_usize), |(sum, count), t| {
+            (sum + t.to_kelvin().into_inner(), count + 1)
+        });
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(Temperature::Kelvin(sum / count as Float))
+    }
+
+    /// Finds the physically hottest reading, returning it in its original
+    /// unit.
+    ///
+    /// `NaN` readings are skipped. Returns `None` if there's nothing else.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureIteratorExt};
+    /// #
+    /// let readings = vec![Temperature::Celsius(30.0), Temperature::Fahrenheit(80.0)];
+    /// assert_eq!(readings.into_iter().max_temperature(), Some(Temperature::Celsius(30.0)));
+    /// ```
+    fn max_temperature(self) -> Option<Temperature> {
+        self.filter(|t| !t.is_nan()).fold(None, |max, t| match max {
+            Some(m) if t.to_kelvin().into_inner() <= m.to_kelvin().into_inner() => Some(m),
+            _ => Some(t),
+        })
+    }
+
+    /// Finds the physically coldest reading, returning it in its original
+    /// unit.
+    ///
+    /// `NaN` readings are skipped. Returns `None` if there's nothing else.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureIteratorExt};
+    /// #
+    /// let readings = vec![Temperature::Celsius(0.0), Temperature::Fahrenheit(31.0)];
+    /// assert_eq!(readings.into_iter().min_temperature(), Some(Temperature::Fahrenheit(31.0)));
+    /// ```
+    fn min_temperature(self) -> Option<Temperature> {
+        self.filter(|t| !t.is_nan()).fold(None, |min, t| match min {
+            Some(m) if t.to_kelvin().into_inner() >= m.to_kelvin().into_inner() => Some(m),
+            _ => Some(t),
+        })
+    }
+}
+
+impl<I> TemperatureIteratorExt for I where I: Iterator<Item = Temperature> {}
+
+impl Temperature {
+    /// Averages only the readings that are physically within `lower` and
+    /// `upper`, returning the mean in Kelvin.
+    ///
+    /// Both bounds are inclusive (see [Temperature::is_within]), so a reading
+    /// sitting right on a bound is kept. Everything else, including `NaN`, is
+    /// thrown out as an outlier. Returns `None` if no readings are left.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let readings = [
+    ///     Temperature::Celsius(20.0),
+    ///     Temperature::Celsius(22.0),
+    ///     Temperature::Celsius(850.0), // a loose wire!
+    /// ];
+    ///
+    /// let mean = Temperature::robust_mean(
+    ///     &readings,
+    ///     Temperature::Celsius(-40.0),
+    ///     Temperature::Celsius(125.0),
+    /// );
+    /// assert_approx_eq!(mean.unwrap().to_celsius().into_inner(), 21.0);
+    /// ```
+    pub fn robust_mean(
+        temps: &[Temperature],
+        lower: Temperature,
+        upper: Temperature,
+    ) -> Option<Temperature> {
+        temps
+            .iter()
+            .filter(|t| t.is_within(lower, upper))
+            .copied()
+            .mean_temperature()
+    }
+
+    /// Finds the physically coldest temperature in `temps`, returning the
+    /// element itself (in its original unit).
+    ///
+    /// This is [TemperatureIteratorExt::min_temperature] for slices, so
+    /// `NaN`s are skipped and ties go to the first one. Returns `None` if
+    /// there's nothing else.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let readings = [Temperature::Celsius(-10.0), Temperature::Fahrenheit(0.0)];
+    /// assert_eq!(Temperature::coldest(&readings), Some(Temperature::Fahrenheit(0.0)));
+    /// ```
+    pub fn coldest(temps: &[Temperature]) -> Option<Temperature> {
+        temps.iter().copied().min_temperature()
+    }
+
+    /// Finds the physically hottest temperature in `temps`, returning the
+    /// element itself (in its original unit).
+    ///
+    /// This is [TemperatureIteratorExt::max_temperature] for slices, so
+    /// `NaN`s are skipped and ties go to the first one. Returns `None` if
+    /// there's nothing else.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let readings = [Temperature::Kelvin(300.0), Temperature::Celsius(20.0)];
+    /// assert_eq!(Temperature::hottest(&readings), Some(Temperature::Kelvin(300.0)));
+    /// ```
+    pub fn hottest(temps: &[Temperature]) -> Option<Temperature> {
+        temps.iter().copied().max_temperature()
+    }
+
+    /// Moves every finite temperature in `temps` to the front, keeping their
+    /// order, and returns how many there are.
+    ///
+    /// Slices can't shrink, so this compacts in place instead. After it
+    /// runs, `&temps[..len]` holds just the good readings. Whatever's left
+    /// after that is the `NaN` and infinite garbage, in no particular order.
+    /// Nothing is allocated, which makes it a cheap cleanup step for sensor
+    /// buffers before averaging.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let mut buffer = [
+    ///     Temperature::Celsius(20.0),
+    ///     Temperature::Celsius(f64::NAN),
+    ///     Temperature::Celsius(21.0),
+    /// ];
+    ///
+    /// let len = Temperature::retain_finite(&mut buffer);
+    /// assert_eq!(&buffer[..len], [Temperature::Celsius(20.0), Temperature::Celsius(21.0)]);
+    /// ```
+    pub fn retain_finite(temps: &mut [Temperature]) -> usize {
+        let mut len = 0;
+
+        for i in 0..temps.len() {
+            if temps[i].get_inner().is_finite() {
+                temps.swap(len, i);
+                len += 1;
+            }
+        }
+
+        len
+    }
+
+    /// Removes physically duplicate temperatures from `temps`, in place,
+    /// and returns how many are left.
+    ///
+    /// A reading is a duplicate when its Kelvin value is within `epsilon`
+    /// (inclusive) of a reading that was already kept, so `0 °C` and `32 °F`
+    /// collapse into one. The first of each group is the one that stays,
+    /// and the kept readings keep their order.
+    ///
+    /// Like [Temperature::retain_finite], slices can't shrink, so the kept
+    /// readings end up in `&temps[..len]`. `NaN` isn't close to anything,
+    /// so it's never treated as a duplicate.
+    ///
+    /// Every reading is checked against every kept one, so this is
+    /// `O(n²)`. That's fine for sensor buffers, but not for huge data sets.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let mut readings = [
+    ///     Temperature::Celsius(0.0),
+    ///     Temperature::Kelvin(300.0),
+    ///     Temperature::Fahrenheit(32.0),
+    /// ];
+    ///
+    /// let len = Temperature::dedup_physical(&mut readings, 1e-9);
+    /// assert_eq!(&readings[..len], [Temperature::Celsius(0.0), Temperature::Kelvin(300.0)]);
+    /// ```
+    pub fn dedup_physical(temps: &mut [Temperature], epsilon: Float) -> usize {
+        let mut len = 0;
+
+        for i in 0..temps.len() {
+            let k = temps[i].to_kelvin().into_inner();
+
+            let duplicate = temps[..len]
+                .iter()
+                .any(|kept| (kept.to_kelvin().into_inner() - k).abs() <= epsilon);
+
+            if !duplicate {
+                temps.swap(len, i);
+                len += 1;
+            }
+        }
+
+        len
+    }
+
+    /// Checks that every temperature in `temps` is *strictly* hotter than
+    /// the one before it, comparing physically (in Kelvin).
+    ///
+    /// This is the check you want for lookup tables, like a thermocouple's
+    /// calibration points: two entries at the same temperature would make
+    /// interpolation between them meaningless. For a check that allows
+    /// equal neighbors, use [Temperature::is_sorted_physical].
+    ///
+    /// Units can be mixed. Keep in mind that two readings which *should* be
+    /// equal across units (like `0 °C` and `32 °F`) might land a rounding
+    /// error apart in Kelvin, so don't lean on those for strictness.
+    ///
+    /// Empty and single-element slices count as increasing. Any `NaN` makes
+    /// this `false`, since it can't be compared.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let table = [
+    ///     Temperature::Celsius(-40.0),
+    ///     Temperature::Fahrenheit(32.0),
+    ///     Temperature::Kelvin(373.15),
+    /// ];
+    /// assert!(Temperature::is_monotonic_increasing(&table));
+    ///
+    /// let repeated = [Temperature::Celsius(10.0), Temperature::Celsius(10.0)];
+    /// assert!(!Temperature::is_monotonic_increasing(&repeated));
+    /// ```
+    pub fn is_monotonic_increasing(temps: &[Temperature]) -> bool {
+        temps
+            .windows(2)
+            .all(|pair| pair[0].to_kelvin().into_inner() < pair[1].to_kelvin().into_inner())
+    }
+
+    /// Checks that `temps` is sorted from coldest to hottest, comparing
+    /// physically (in Kelvin).
+    ///
+    /// Unlike [Temperature::is_monotonic_increasing], this isn't strict:
+    /// neighbors can be equal. Empty and single-element slices are sorted,
+    /// and any `NaN` makes this `false`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let readings = [
+    ///     Temperature::Celsius(10.0),
+    ///     Temperature::Celsius(10.0),
+    ///     Temperature::Kelvin(300.0),
+    /// ];
+    /// assert!(Temperature::is_sorted_physical(&readings));
+    /// ```
+    pub fn is_sorted_physical(temps: &[Temperature]) -> bool {
+        temps
+            .windows(2)
+            .all(|pair| pair[0].to_kelvin().into_inner() <= pair[1].to_kelvin().into_inner())
+    }
+
+    /// Folds over the Kelvin values of `temps`, wrapping the result back up
+    /// as a Kelvin [Temperature].
+    ///
+    /// This is a building block for your own aggregations. Every reading is
+    /// normalized to Kelvin before it reaches `f`, so you don't have to
+    /// think about units. Returns `None` if `temps` is empty.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let readings = [Temperature::Kelvin(3.0), Temperature::Kelvin(4.0)];
+    ///
+    /// // root-mean-square, starting with the sum of squares
+    /// let squares = Temperature::reduce_kelvin(&readings, 0.0, |acc, k| acc + k * k).unwrap();
+    /// let rms = (squares.into_inner() / readings.len() as f64).sqrt();
+    ///
+    /// assert_approx_eq!(rms, 3.5355, 1e-4);
+    /// ```
+    pub fn reduce_kelvin<F: Fn(Float, Float) -> Float>(
+        temps: &[Temperature],
+        init: Float,
+        f: F,
+    ) -> Option<Temperature> {
+        if temps.is_empty() {
+            return None;
+        }
+
+        let result = temps
+            .iter()
+            .fold(init, |acc, t| f(acc, t.to_kelvin().into_inner()));
+
+        Some(Temperature::Kelvin(result))
+    }
+
+    /// Averages irregularly-spaced samples, weighting each by how long it
+    /// lasted.
+    ///
+    /// Each sample is a `(temperature, timestamp)` pair, with the timestamp
+    /// in seconds. The average uses the trapezoidal rule in Kelvin, so a
+    /// reading that held for a long time counts more than a quick blip. The
+    /// result is in the first sample's unit.
+    ///
+    /// The samples **must be sorted** by timestamp. Returns `None` when:
+    ///
+    /// - there are fewer than two samples,
+    /// - any timestamp is `NaN` or infinite,
+    /// - the timestamps go backwards, or
+    /// - no time passes between the first and last sample.
+    ///
+    /// A `NaN` *temperature* still gives you a `NaN` mean, just like
+    /// averaging any other way would.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let samples = [
+    ///     (Temperature::Celsius(20.0), 0.0),
+    ///     (Temperature::Celsius(20.0), 9.0), // steady for a while...
+    ///     (Temperature::Celsius(30.0), 10.0), // ...then a quick jump
+    /// ];
+    ///
+    /// let mean = Temperature::time_weighted_mean(&samples).unwrap();
+    /// assert_approx_eq!(mean.into_inner(), 20.5);
+    /// ```
+    pub fn time_weighted_mean(samples: &[(Temperature, Float)]) -> Option<Temperature> {
+        let (first, last) = match samples {
+            [first, .., last] => (first, last),
+            _ => return None,
+        };
+
+        let mut area = 0.0;
+
+        for pair in samples.windows(2) {
+            let [(a, t_a), (b, t_b)] = pair else {
+                continue;
+            };
+
+            let dt = t_b - t_a;
+            if !dt.is_finite() || dt < 0.0 {
+                return None;
+            }
+
+            let (k_a, k_b) = (a.to_kelvin().into_inner(), b.to_kelvin().into_inner());
+            area += (k_a + k_b) / 2.0 * dt;
+        }
+
+        let duration = last.1 - first.1;
+        if duration <= 0.0 {
+            return None;
+        }
+
+        Some(Temperature::Kelvin(area / duration).to_unit(first.0.unit()))
+    }
+}
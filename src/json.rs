@@ -0,0 +1,308 @@
+//! # JSON
+//!
+//! A tiny JSON format for [Temperature]s, without `serde`.
+//!
+//! A temperature is written as an object with its unit's name and its value:
+//!
+//! ```text
+//! {"unit":"Celsius","value":25.0}
+//! ```
+//!
+//! The unit is always one of `"Fahrenheit"`, `"Celsius"`, or `"Kelvin"`.
+//! The value is a plain JSON number with up to five decimal places. (Huge
+//! values, at or beyond 10¹⁴, use an exponent instead, like `1.41678e32`.)
+//! JSON has no way to write `NaN` or infinity, so those become `null`.
+//!
+//! Everything goes through `ufmt`, so it works on `no_std` without pulling
+//! in `core::fmt`'s float formatting.
+//!
+//! Reading it back in with [Temperature::from_json] is a bit more relaxed.
+//! Whitespace is fine anywhere JSON allows it, the fields can be in either
+//! order, and `null` reads as `NaN`. String escapes aren't supported, since
+//! no unit name needs them.
+
+use crate::{Float, ParseTemperatureError, Temperature, Unit};
+
+/// Values at or above this are written with an exponent.
+const EXPONENT_THRESHOLD: Float = 1e14;
+
+/// How many decimal places to write (before trimming trailing zeros).
+const DECIMALS: usize = 5;
+
+/// `10^DECIMALS`, for pulling out the fraction's digits.
+const DECIMAL_SCALE: Float = 100_000.0;
+
+/// Returns the JSON name of a [Unit].
+const fn unit_name(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Fahrenheit => "Fahrenheit",
+        Unit::Celsius => "Celsius",
+        Unit::Kelvin => "Kelvin",
+    }
+}
+
+/// Returns the [Unit] with the given JSON name.
+fn unit_from_name(name: &str) -> Option<Unit> {
+    match name {
+        "Fahrenheit" => Some(Unit::Fahrenheit),
+        "Celsius" => Some(Unit::Celsius),
+        "Kelvin" => Some(Unit::Kelvin),
+        _ => None,
+    }
+}
+
+/// Writes `value` as a JSON number (or `null`).
+fn uwrite_number<W>(f: &mut ufmt::Formatter<'_, W>, value: Float) -> Result<(), W::Error>
+where
+    W: ufmt_write::uWrite + ?Sized,
+{
+    if !value.is_finite() {
+        return f.write_str("null");
+    }
+
+    if value < 0.0 {
+        f.write_str("-")?;
+    }
+
+    let mut abs = value.abs();
+    let mut exponent: u16 = 0;
+
+    if abs >= EXPONENT_THRESHOLD {
+        while abs >= 10.0 {
+            abs /= 10.0;
+            exponent += 1;
+        }
+    }
+
+    // rounds to the last decimal place. this fits: `abs` is under 10^14
+    let scaled = (abs * DECIMAL_SCALE + 0.5) as u64;
+    let whole = scaled / DECIMAL_SCALE as u64;
+    let mut fraction = scaled % DECIMAL_SCALE as u64;
+
+    let mut digits = [b'0'; DECIMALS];
+    for digit in digits.iter_mut().rev() {
+        *digit = b'0' + (fraction % 10) as u8;
+        fraction /= 10;
+    }
+
+    // drop trailing zeros, but always keep one digit
+    let len = digits.iter().rposition(|d| *d != b'0').map_or(1, |i| i + 1);
+
+    ufmt::uwrite!(f, "{}.", whole)?;
+    for digit in &digits[..len] {
+        f.write_char(*digit as char)?;
+    }
+
+    if exponent > 0 {
+        ufmt::uwrite!(f, "e{}", exponent)?;
+    }
+
+    Ok(())
+}
+
+/// A [Temperature] that `ufmt` displays as JSON.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Json(Temperature);
+
+impl ufmt::uDisplay for Json {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        f.write_str("{\"unit\":\"")?;
+        f.write_str(unit_name(self.0.unit()))?;
+        f.write_str("\",\"value\":")?;
+        uwrite_number(f, self.0.get_inner())?;
+        f.write_str("}")
+    }
+}
+
+impl Temperature {
+    /// Returns something that `ufmt` writes as a JSON object, like
+    /// `{"unit":"Celsius","value":25.0}`.
+    ///
+    /// See the [module docs](crate::json) for the exact format. The output
+    /// is never longer than 64 bytes, so a 64-byte buffer always fits it.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// # struct Out(String);
+    /// #
+    /// # impl ufmt_write::uWrite for Out {
+    /// #     type Error = core::convert::Infallible;
+    /// #
+    /// #     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    /// #         self.0.push_str(s);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// let mut out = Out(String::new());
+    /// ufmt::uwrite!(out, "{}", Temperature::Celsius(25.0).to_json()).unwrap();
+    ///
+    /// assert_eq!(out.0, r#"{"unit":"Celsius","value":25.0}"#);
+    /// ```
+    pub fn to_json(&self) -> impl ufmt::uDisplay {
+        Json(*self)
+    }
+}
+
+/// A tiny cursor for reading through JSON text.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Skips over any JSON whitespace.
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        let trimmed = rest.trim_start_matches([' ', '\t', '\n', '\r']);
+
+        self.pos += rest.len() - trimmed.len();
+    }
+
+    /// Returns the next character (after whitespace) without taking it.
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Takes the next character (after whitespace) if it's `expected`.
+    fn expect(&mut self, expected: char, error: &'static str) -> Result<(), ParseTemperatureError> {
+        if self.peek() != Some(expected) {
+            return Err(ParseTemperatureError::Malformed(error));
+        }
+
+        self.pos += expected.len_utf8();
+        Ok(())
+    }
+
+    /// Reads a string without escapes, returning what's between the quotes.
+    fn string(&mut self) -> Result<&'a str, ParseTemperatureError> {
+        self.expect('"', "expected a string")?;
+
+        let rest = &self.input[self.pos..];
+        let end = rest
+            .find(['"', '\\'])
+            .ok_or(ParseTemperatureError::Malformed("unterminated string"))?;
+
+        if rest[end..].starts_with('\\') {
+            return Err(ParseTemperatureError::Malformed(
+                "string escapes aren't supported",
+            ));
+        }
+
+        self.pos += end + 1;
+        Ok(&rest[..end])
+    }
+
+    /// Reads a number, or `null` as `NaN`.
+    fn number(&mut self) -> Result<Float, ParseTemperatureError> {
+        self.skip_whitespace();
+
+        let rest = &self.input[self.pos..];
+
+        if let Some(after) = rest.strip_prefix("null") {
+            self.pos += rest.len() - after.len();
+            return Ok(Float::NAN);
+        }
+
+        let len = rest
+            .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            .unwrap_or(rest.len());
+
+        // `parse` is a little more forgiving than JSON, but it never
+        // accepts anything that isn't a number
+        let value = rest[..len]
+            .parse::<Float>()
+            .map_err(|_| ParseTemperatureError::InvalidNumber)?;
+
+        self.pos += len;
+        Ok(value)
+    }
+}
+
+impl Temperature {
+    /// Parses a [Temperature] from the JSON object written by
+    /// [Temperature::to_json], like `{"unit":"Celsius","value":25.0}`.
+    ///
+    /// This is a tiny hand-written parser, so it works on `no_std` without
+    /// `serde`. It takes whitespace anywhere JSON does, and the fields can
+    /// come in either order. Both fields are required, and anything else
+    /// (extra fields, duplicates, trailing text) is an error.
+    ///
+    /// # Errors
+    ///
+    /// - [ParseTemperatureError::Malformed], when the text isn't the JSON
+    ///   object above
+    /// - [ParseTemperatureError::MissingField], when `unit` or `value` isn't
+    ///   there
+    /// - [ParseTemperatureError::UnknownUnit], when the unit isn't
+    ///   `"Fahrenheit"`, `"Celsius"`, or `"Kelvin"`
+    /// - [ParseTemperatureError::InvalidNumber], when the value isn't a
+    ///   number (or `null`)
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let temp = Temperature::from_json(r#"{ "value": 25.0, "unit": "Celsius" }"#);
+    /// assert_eq!(temp, Ok(Temperature::Celsius(25.0)));
+    ///
+    /// assert!(Temperature::from_json(r#"{"unit":"Rankine","value":500}"#).is_err());
+    /// ```
+    pub fn from_json(s: &str) -> Result<Temperature, ParseTemperatureError> {
+        let mut parser = Parser { input: s, pos: 0 };
+
+        let mut unit = None;
+        let mut value = None;
+
+        parser.expect('{', "expected `{`")?;
+
+        if parser.peek() != Some('}') {
+            loop {
+                let key = parser.string()?;
+                parser.expect(':', "expected `:` after a key")?;
+
+                match key {
+                    "unit" if unit.is_none() => {
+                        let name = parser.string()?;
+                        unit =
+                            Some(unit_from_name(name).ok_or(ParseTemperatureError::UnknownUnit)?);
+                    }
+                    "value" if value.is_none() => value = Some(parser.number()?),
+                    "unit" | "value" => {
+                        return Err(ParseTemperatureError::Malformed("duplicate field"))
+                    }
+                    _ => return Err(ParseTemperatureError::Malformed("unknown field")),
+                }
+
+                if parser.peek() == Some(',') {
+                    parser.pos += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        parser.expect('}', "expected `}`")?;
+
+        if parser.peek().is_some() {
+            return Err(ParseTemperatureError::Malformed(
+                "unexpected text after `}`",
+            ));
+        }
+
+        let unit = unit.ok_or(ParseTemperatureError::MissingField("unit"))?;
+        let value = value.ok_or(ParseTemperatureError::MissingField("value"))?;
+
+        Ok(Temperature::from((value, unit)))
+    }
+}
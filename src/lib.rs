@@ -75,6 +75,29 @@ pub mod checked;
 #[cfg(all(any(feature = "checked", doc), std))]
 pub use self::checked::CheckedTemperature;
 
+pub mod calibration;
+pub mod comfort;
+pub mod convert;
+pub mod display;
+pub mod iter;
+pub mod json;
+mod math;
+pub mod physics;
+pub mod reference;
+pub mod sample;
+pub mod scale;
+pub mod sensor;
+pub mod strict;
+pub mod substance;
+pub mod traced;
+pub mod tracking;
+pub mod typed;
+
+pub use self::calibration::Calibration;
+pub use self::iter::TemperatureIteratorExt;
+
+use onlyerror::Error;
+
 #[cfg(not(feature = "f32"))]
 type Float = f64;
 
@@ -103,7 +126,154 @@ pub enum Temperature {
     Kelvin(self::Float),
 }
 
+/// The unit of a [Temperature], without any value attached.
+///
+/// This is handy when you're tracking a unit separately from its value at
+/// runtime.
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// use simmer::{Temperature, Unit};
+///
+/// let my_temp = Temperature::from((25.0, Unit::Celsius));
+/// assert_eq!(my_temp.unit(), Unit::Celsius);
+///```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(all(feature = "arbitrary", std), derive(arbitrary::Arbitrary))]
+pub enum Unit {
+    Fahrenheit,
+    Celsius,
+    Kelvin,
+}
+
+/// An error regarding a plain [Temperature].
+#[derive(Clone, Copy, Debug, PartialEq, Error)]
+pub enum TemperatureError {
+    #[error("The temperature's value was NaN.")]
+    IsNan,
+    #[error("The temperature's value, {0}, was infinite.")]
+    IsInfinite(Float),
+}
+
+/// An error from parsing a [Temperature] out of text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum ParseTemperatureError {
+    #[error("The input was malformed: {0}.")]
+    Malformed(&'static str),
+    #[error("The input was missing its `{0}`.")]
+    MissingField(&'static str),
+    #[error("The unit wasn't one that simmer knows about.")]
+    UnknownUnit,
+    #[error("The value wasn't a valid number.")]
+    InvalidNumber,
+}
+
 impl Temperature {
+    /// Creates a new [Temperature] in Fahrenheit.
+    ///
+    /// Same as `Temperature::Fahrenheit(value)`, but reads a bit nicer.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let body_temp = Temperature::fahrenheit(98.6);
+    /// assert_eq!(body_temp, Temperature::Fahrenheit(98.6));
+    /// ```
+    pub const fn fahrenheit(value: Float) -> Temperature {
+        Temperature::Fahrenheit(value)
+    }
+
+    /// Creates a new [Temperature] in Celsius.
+    ///
+    /// Same as `Temperature::Celsius(value)`, but reads a bit nicer.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let room_temp = Temperature::celsius(25.0);
+    /// assert_eq!(room_temp, Temperature::Celsius(25.0));
+    /// ```
+    pub const fn celsius(value: Float) -> Temperature {
+        Temperature::Celsius(value)
+    }
+
+    /// Creates a new [Temperature] in Kelvin.
+    ///
+    /// Same as `Temperature::Kelvin(value)`, but reads a bit nicer.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let ice = Temperature::kelvin(273.15);
+    /// assert_eq!(ice, Temperature::Kelvin(273.15));
+    /// ```
+    pub const fn kelvin(value: Float) -> Temperature {
+        Temperature::Kelvin(value)
+    }
+
+    /// Creates a new [Temperature], but only if `value` is finite.
+    ///
+    /// Returns `None` for `NaN` and infinite values. Unlike
+    /// [CheckedTemperature](crate::checked), this doesn't care about
+    /// absolute zero or any bounds - it's just a quick finiteness check.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// assert_eq!(
+    ///     Temperature::new_finite(Unit::Celsius, 21.0),
+    ///     Some(Temperature::Celsius(21.0))
+    /// );
+    /// assert_eq!(Temperature::new_finite(Unit::Celsius, f64::NAN), None);
+    /// ```
+    pub fn new_finite(unit: Unit, value: Float) -> Option<Temperature> {
+        Temperature::from((value, unit)).finite()
+    }
+
+    /// Creates a new [Temperature] from an `f64`, even when the crate is
+    /// built with the `f32` feature.
+    ///
+    /// With `f32`, a plain `as` cast turns any `f64` past `f32::MAX` into
+    /// infinity. This clamps finite values into the `f32` range first, so
+    /// you get `f32::MAX` (or `f32::MIN`) instead. That's handy when a host
+    /// sends `f64` data to a device.
+    ///
+    /// Without `f32`, `value` is used as-is. Either way, `NaN` stays `NaN`
+    /// and infinities stay infinite.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let from_host = Temperature::from_f64_saturating(Unit::Kelvin, 1e300);
+    ///
+    /// // finite on either build
+    /// assert!(from_host.get_inner().is_finite());
+    /// ```
+    pub fn from_f64_saturating(unit: Unit, value: f64) -> Temperature {
+        #[cfg(feature = "f32")]
+        let value = if value.is_finite() {
+            value.clamp(f32::MIN as f64, f32::MAX as f64) as f32
+        } else {
+            value as f32
+        };
+
+        Temperature::from((value, unit))
+    }
+
     /// Return a Temperature in Fahrenheit based off of Self.
     ///
     /// # Usage
@@ -169,6 +339,69 @@ impl Temperature {
         }
     }
 
+    /// Clamps an infinite inner value to the largest finite `Float` with the
+    /// same sign.
+    fn saturate(self) -> Temperature {
+        Temperature::from((self.get_inner().clamp(Float::MIN, Float::MAX), self.unit()))
+    }
+
+    /// Like [Temperature::to_fahrenheit], but saturates instead of overflowing.
+    ///
+    /// Very large values can overflow to infinity when converted, especially
+    /// with the `f32` feature. This clamps the result to `Float::MAX` (or
+    /// `Float::MIN`) instead. `NaN` stays `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let huge = Temperature::Kelvin(f64::MAX);
+    ///
+    /// assert!(huge.to_fahrenheit().get_inner().is_infinite());
+    /// assert_eq!(huge.to_fahrenheit_saturating().get_inner(), f64::MAX);
+    /// ```
+    pub fn to_fahrenheit_saturating(&self) -> Temperature {
+        self.to_fahrenheit().saturate()
+    }
+
+    /// Like [Temperature::to_celsius], but saturates instead of overflowing.
+    ///
+    /// Infinite results are clamped to `Float::MAX` (or `Float::MIN`).
+    /// `NaN` stays `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let cold = Temperature::Fahrenheit(f64::NEG_INFINITY);
+    /// assert_eq!(cold.to_celsius_saturating().get_inner(), f64::MIN);
+    /// ```
+    pub fn to_celsius_saturating(&self) -> Temperature {
+        self.to_celsius().saturate()
+    }
+
+    /// Like [Temperature::to_kelvin], but saturates instead of overflowing.
+    ///
+    /// Infinite results are clamped to `Float::MAX` (or `Float::MIN`).
+    /// `NaN` stays `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let hot = Temperature::Celsius(f64::INFINITY);
+    /// assert_eq!(hot.to_kelvin_saturating().get_inner(), f64::MAX);
+    /// ```
+    pub fn to_kelvin_saturating(&self) -> Temperature {
+        self.to_kelvin().saturate()
+    }
+
     /// A discovery function that returns the inner type, consuming the outer Temperature type.
     /// Use `my_temp.into()` when possible.
     ///
@@ -185,6 +418,37 @@ impl Temperature {
         Into::<Float>::into(self)
     }
 
+    /// Like [Temperature::into_inner], but only returns the inner value if it's
+    /// finite.
+    ///
+    /// You'll get a [TemperatureError] if the value is `NaN` or infinite.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureError};
+    /// #
+    /// let temp = Temperature::Celsius(21.5);
+    /// assert_eq!(temp.try_into_inner(), Ok(21.5));
+    ///
+    /// let broken = Temperature::Celsius(f64::NAN);
+    /// assert_eq!(broken.try_into_inner(), Err(TemperatureError::IsNan));
+    /// ```
+    pub fn try_into_inner(self) -> Result<Float, TemperatureError> {
+        let value = self.into_inner();
+
+        if value.is_nan() {
+            return Err(TemperatureError::IsNan);
+        }
+
+        if value.is_infinite() {
+            return Err(TemperatureError::IsInfinite(value));
+        }
+
+        Ok(value)
+    }
+
     /// Gets the inner floating point value.
     ///
     /// # Usage
@@ -206,115 +470,1299 @@ impl Temperature {
         }
     }
 
-    /// Tells you if a [Temperature] is below absolute zero - an invalid state
-    /// for temperature.
+    /// Applies `f` to the inner value, keeping the same unit.
     ///
-    /// So... returns:
-    /// - `true` if `t` >= abs zero
-    /// - `false` if `t` < abs zero
+    /// This is an escape hatch for any transform the crate doesn't have,
+    /// like a calibration polynomial, without matching on every variant.
+    /// The unit never changes, and nothing checks the result - `f` can
+    /// happily return `NaN` or something below absolute zero.
     ///
     /// # Usage
     ///
     #[cfg_attr(feature = "f32", doc = "```ignore")]
     #[cfg_attr(not(feature = "f32"), doc = "```")]
     /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
     /// #
-    /// let temp = Temperature::Kelvin(0.0);
-    /// assert!(!temp.is_below_abs_zero());
+    /// // this sensor reads 2% high, with a 0.5 degree offset
+    /// let raw = Temperature::Celsius(25.0);
+    /// let corrected = raw.map_temp(|c| c / 1.02 - 0.5);
     ///
-    /// let temp2 = Temperature::Kelvin(-0.1);
-    /// assert!(temp2.is_below_abs_zero());
+    /// assert_approx_eq!(corrected.into_inner(), 24.0098, 1e-4);
     /// ```
-    pub fn is_below_abs_zero(&self) -> bool {
-        match self {
-            Temperature::Fahrenheit(f) => *f < -459.67,
-            Temperature::Celsius(c) => *c < -273.15,
-            Temperature::Kelvin(k) => *k < 0.0,
-        }
+    pub fn map_temp<F: Fn(Float) -> Float>(self, f: F) -> Temperature {
+        Temperature::from((f(self.get_inner()), self.unit()))
     }
 
-    /// Checks if the internal floating point number is `NaN`.
+    /// Returns the [Unit] that this temperature is stored in.
     ///
     /// # Usage
     ///
     #[cfg_attr(feature = "f32", doc = "```ignore")]
     #[cfg_attr(not(feature = "f32"), doc = "```")]
-    /// # use simmer::Temperature;
+    /// # use simmer::{Temperature, Unit};
     /// #
-    /// let temp = Temperature::Fahrenheit(f64::NAN);
-    /// assert!(temp.is_nan());
+    /// let temp = Temperature::Kelvin(0.0);
+    /// assert_eq!(temp.unit(), Unit::Kelvin);
     /// ```
-    pub fn is_nan(&self) -> bool {
+    pub const fn unit(&self) -> Unit {
         match self {
-            Temperature::Celsius(t) | Temperature::Fahrenheit(t) | Temperature::Kelvin(t) => {
-                t.is_nan()
-            }
+            Temperature::Fahrenheit(_) => Unit::Fahrenheit,
+            Temperature::Celsius(_) => Unit::Celsius,
+            Temperature::Kelvin(_) => Unit::Kelvin,
         }
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<Float> for Temperature {
-    fn into(self) -> Float {
-        match self {
-            Temperature::Fahrenheit(f) => f,
-            Temperature::Celsius(c) => c,
-            Temperature::Kelvin(k) => k,
+    /// Converts `self` into the given [Unit].
+    ///
+    /// This is the same as calling [Temperature::to_fahrenheit],
+    /// [Temperature::to_celsius], or [Temperature::to_kelvin], but you can pick
+    /// the unit at runtime.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let boiling = Temperature::Celsius(100.0).to_unit(Unit::Fahrenheit);
+    /// assert_approx_eq!(boiling.into_inner(), 212.0);
+    /// ```
+    pub fn to_unit(&self, unit: Unit) -> Temperature {
+        match unit {
+            Unit::Fahrenheit => self.to_fahrenheit(),
+            Unit::Celsius => self.to_celsius(),
+            Unit::Kelvin => self.to_kelvin(),
         }
     }
-}
 
-// various display impls
+    /// Like [Temperature::to_unit], but fails instead of passing along a
+    /// non-finite value.
+    ///
+    /// The plain conversions happily turn `NaN` into more `NaN`. This one
+    /// gives you a [TemperatureError] if `self` is `NaN` or infinite, or if
+    /// the conversion itself overflows to infinity (like converting
+    /// `Float::MAX` Celsius into Fahrenheit).
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureError, Unit};
+    /// #
+    /// let ice = Temperature::Celsius(0.0).try_to_unit(Unit::Kelvin);
+    /// assert_eq!(ice, Ok(Temperature::Kelvin(273.15)));
+    ///
+    /// let broken = Temperature::Celsius(f64::NAN).try_to_unit(Unit::Kelvin);
+    /// assert_eq!(broken, Err(TemperatureError::IsNan));
+    /// ```
+    pub fn try_to_unit(&self, unit: Unit) -> Result<Temperature, TemperatureError> {
+        self.try_into_inner()?;
 
-impl core::fmt::Display for Temperature {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.get_inner())
-    }
-}
+        let converted = self.to_unit(unit);
+        converted.try_into_inner()?;
 
-impl ufmt::uDebug for Temperature {
-    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: ufmt_write::uWrite + ?Sized,
-    {
-        let unit = match self {
-            Temperature::Fahrenheit(_) => "Fahrenheit",
-            Temperature::Celsius(_) => "Celsius",
-            Temperature::Kelvin(_) => "Kelvin",
-        };
+        Ok(converted)
+    }
 
-        #[cfg(feature = "f32")]
-        return ufmt::uwrite!(
-            f,
-            "Temperature::{}({})",
-            unit,
-            ufmt_float::uFmt_f32::Five(self.get_inner())
-        );
+    /// Converts to Fahrenheit, failing on non-finite values. See
+    /// [Temperature::try_to_unit].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let boiling = Temperature::Celsius(100.0).try_to_fahrenheit();
+    /// assert_eq!(boiling, Ok(Temperature::Fahrenheit(212.0)));
+    /// ```
+    pub fn try_to_fahrenheit(&self) -> Result<Temperature, TemperatureError> {
+        self.try_to_unit(Unit::Fahrenheit)
+    }
 
-        #[cfg(not(feature = "f32"))]
-        return ufmt::uwrite!(
-            f,
-            "Temperature::{}({})",
-            unit,
-            ufmt_float::uFmt_f64::Five(self.get_inner())
-        );
+    /// Converts to Celsius, failing on non-finite values. See
+    /// [Temperature::try_to_unit].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, TemperatureError};
+    /// #
+    /// let hot = Temperature::Kelvin(f64::INFINITY).try_to_celsius();
+    /// assert_eq!(hot, Err(TemperatureError::IsInfinite(f64::INFINITY)));
+    /// ```
+    pub fn try_to_celsius(&self) -> Result<Temperature, TemperatureError> {
+        self.try_to_unit(Unit::Celsius)
     }
-}
 
-impl ufmt::uDisplay for Temperature {
-    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
-    where
-        W: ufmt_write::uWrite + ?Sized,
-    {
-        #[cfg(feature = "f32")]
-        return ufmt::uwrite!(f, "{}", ufmt_float::uFmt_f32::Five(self.get_inner()));
+    /// Converts to Kelvin, failing on non-finite values. See
+    /// [Temperature::try_to_unit].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let ice = Temperature::Celsius(0.0).try_to_kelvin();
+    /// assert_eq!(ice, Ok(Temperature::Kelvin(273.15)));
+    /// ```
+    pub fn try_to_kelvin(&self) -> Result<Temperature, TemperatureError> {
+        self.try_to_unit(Unit::Kelvin)
+    }
 
-        #[cfg(not(feature = "f32"))]
-        return ufmt::uwrite!(f, "{}", ufmt_float::uFmt_f64::Five(self.get_inner()));
+    /// Converts `self` into all three units at once, returning
+    /// `(celsius, fahrenheit, kelvin)`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let (c, f, k) = Temperature::Celsius(100.0).to_all();
+    ///
+    /// assert_approx_eq!(c.into_inner(), 100.0);
+    /// assert_approx_eq!(f.into_inner(), 212.0);
+    /// assert_approx_eq!(k.into_inner(), 373.15);
+    /// ```
+    pub fn to_all(&self) -> (Temperature, Temperature, Temperature) {
+        (self.to_celsius(), self.to_fahrenheit(), self.to_kelvin())
     }
-}
 
-// operator overloading impls
+    /// Converts `self` into the given [Unit], also telling you if a
+    /// conversion actually happened.
+    ///
+    /// The `bool` is `false` when `self` was already in that unit (so
+    /// nothing was done), and `true` otherwise. That's handy for counting
+    /// conversions in a hot path.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let room = Temperature::Celsius(20.0);
+    ///
+    /// let (_, converted) = room.convert_tracked(Unit::Celsius);
+    /// assert!(!converted);
+    ///
+    /// let (_, converted) = room.convert_tracked(Unit::Kelvin);
+    /// assert!(converted);
+    /// ```
+    pub fn convert_tracked(&self, to: Unit) -> (Temperature, bool) {
+        (self.to_unit(to), self.unit() != to)
+    }
+
+    /// Replaces a negative zero (`-0.0`) inner value with a positive one,
+    /// keeping the unit.
+    ///
+    /// IEEE 754 floats have two zeros. They're equal with `==` (so the
+    /// derived `PartialEq` already treats `Celsius(-0.0)` and `Celsius(0.0)`
+    /// the same), and neither is below absolute zero. But the sign bit is
+    /// still there: it shows up when printing, in `total_cmp`, in
+    /// `copysign`, and when dividing by it (`1.0 / -0.0` is negative
+    /// infinity!). Canonicalizing gets rid of those surprises.
+    ///
+    /// Every other value, including `NaN`, is left alone.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let zero = Temperature::Celsius(-0.0).canonicalize_zero();
+    /// assert!(zero.into_inner().is_sign_positive());
+    /// ```
+    pub fn canonicalize_zero(self) -> Temperature {
+        if self.get_inner() == 0.0 {
+            return Temperature::from((0.0, self.unit()));
+        }
+
+        self
+    }
+
+    /// Canonicalizes `self` into Kelvin.
+    ///
+    /// This does the same thing as [Temperature::to_kelvin]. It's here for
+    /// when you're storing temperatures and want to say *why* you're
+    /// converting: normalized temperatures all share one unit, so comparing
+    /// them is trivial.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let stored = Temperature::Celsius(0.0).normalize();
+    /// assert!(stored.is_normalized());
+    /// ```
+    pub fn normalize(self) -> Temperature {
+        self.to_kelvin()
+    }
+
+    /// Checks if `self` is already normalized (in Kelvin).
+    ///
+    /// You can use this to skip a redundant [Temperature::normalize].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// assert!(Temperature::Kelvin(300.0).is_normalized());
+    /// assert!(!Temperature::Celsius(300.0).is_normalized());
+    /// ```
+    pub const fn is_normalized(&self) -> bool {
+        matches!(self, Temperature::Kelvin(_))
+    }
+
+    /// Converts `self` into another [Unit] and back again, returning how far
+    /// the result drifted from the original value.
+    ///
+    /// Floating point math isn't exact, so conversions can pick up a little
+    /// error. This lets you measure how much on your own target (especially
+    /// with the `f32` feature). The error is an absolute difference in
+    /// `self`'s unit.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let boiling = Temperature::Celsius(100.0);
+    /// assert!(boiling.round_trip_error(Unit::Fahrenheit) < 1e-9);
+    /// ```
+    pub fn round_trip_error(&self, via: Unit) -> Float {
+        let there_and_back = self.to_unit(via).to_unit(self.unit());
+
+        (there_and_back.get_inner() - self.get_inner()).abs()
+    }
+
+    /// Converts `self` into the given [Unit], also telling you if the
+    /// conversion lost more than `epsilon` of precision.
+    ///
+    /// The `bool` is `true` when converting back into `self`'s unit differs
+    /// from the original value by more than `epsilon` (see
+    /// [Temperature::round_trip_error]). Non-finite values always count as
+    /// lossy.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let (converted, lossy) = Temperature::Celsius(100.0).convert_lossy_check(Unit::Kelvin, 1e-9);
+    ///
+    /// assert_eq!(converted, Temperature::Kelvin(373.15));
+    /// assert!(!lossy);
+    /// ```
+    pub fn convert_lossy_check(&self, to: Unit, epsilon: Float) -> (Temperature, bool) {
+        // written this way so that `NaN` errors count as lossy
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
+        let lossy = !(self.round_trip_error(to) <= epsilon);
+
+        (self.to_unit(to), lossy)
+    }
+
+    /// Relabels `self` with another [Unit], keeping the inner value exactly
+    /// as it is.
+    ///
+    /// **Careful**: this does *not* convert anything! `Celsius(100.0)` becomes
+    /// `Fahrenheit(100.0)`, which is a very different temperature. Use it
+    /// only when you know the number was already in the other unit. If you
+    /// want a conversion, use [Temperature::to_unit].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// // oops, that reading was actually in fahrenheit
+    /// let mislabeled = Temperature::Celsius(100.0);
+    /// let fixed = mislabeled.with_unit(Unit::Fahrenheit);
+    ///
+    /// assert_eq!(fixed, Temperature::Fahrenheit(100.0));
+    /// ```
+    pub fn with_unit(self, unit: Unit) -> Temperature {
+        Temperature::from((self.into_inner(), unit))
+    }
+
+    /// Converts `self` into whole millikelvin (thousandths of a Kelvin), for
+    /// storing temperatures without floats.
+    ///
+    /// The value is rounded to the nearest millikelvin, with halves rounded
+    /// away from zero.
+    ///
+    /// An `i32` can hold about ±2,147,483 K. Anything outside of that
+    /// saturates to `i32::MAX` or `i32::MIN`, and `NaN` becomes `0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let room = Temperature::Celsius(21.0);
+    /// assert_eq!(room.to_millikelvin(), 294_150);
+    /// ```
+    pub fn to_millikelvin(&self) -> i32 {
+        let mk = self.to_kelvin().into_inner() * 1000.0;
+
+        // `as` truncates toward zero (and saturates), so nudge it first
+        (mk + Float::copysign(0.5, mk)) as i32
+    }
+
+    /// Creates a Kelvin [Temperature] from whole millikelvin.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let ice = Temperature::from_millikelvin(273_150);
+    /// assert_approx_eq!(ice.to_celsius().into_inner(), 0.0);
+    /// ```
+    pub const fn from_millikelvin(mk: i32) -> Temperature {
+        Temperature::Kelvin(mk as Float / 1000.0)
+    }
+
+    /// Packs `self` into a 32-bit register value, keeping its unit.
+    ///
+    /// The layout is:
+    ///
+    /// | Bits    | Meaning                                              |
+    /// | ------- | ---------------------------------------------------- |
+    /// | 31..=30 | unit tag: `0` Fahrenheit, `1` Celsius, `2` Kelvin    |
+    /// | 29..=0  | signed (two's complement) hundredths of a degree     |
+    ///
+    /// Tag `3` is reserved. The value is rounded to the nearest hundredth,
+    /// with halves rounded away from zero. Thirty bits hold about
+    /// ±5,368,709.11 degrees - anything past that saturates, and `NaN`
+    /// becomes `0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let room = Temperature::Celsius(21.5);
+    /// assert_eq!(room.to_register(), (1 << 30) | 2150);
+    /// ```
+    pub fn to_register(&self) -> u32 {
+        const MAX: i32 = (1 << 29) - 1;
+        const MIN: i32 = -(1 << 29);
+
+        let tag: u32 = match self.unit() {
+            Unit::Fahrenheit => 0,
+            Unit::Celsius => 1,
+            Unit::Kelvin => 2,
+        };
+
+        let hundredths = self.get_inner() * 100.0;
+
+        // `as` truncates toward zero (and saturates), so nudge it first
+        let value = ((hundredths + Float::copysign(0.5, hundredths)) as i32).clamp(MIN, MAX);
+
+        (tag << 30) | (value as u32 & 0x3FFF_FFFF)
+    }
+
+    /// Unpacks a [Temperature] from a 32-bit register value.
+    ///
+    /// See [Temperature::to_register] for the layout. Returns `None` if the
+    /// unit tag is the reserved value, `3`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let reg = Temperature::Kelvin(-0.25).to_register();
+    /// let temp = Temperature::from_register(reg).unwrap();
+    ///
+    /// assert!(matches!(temp, Temperature::Kelvin(_)));
+    /// assert_approx_eq!(temp.into_inner(), -0.25);
+    ///
+    /// assert_eq!(Temperature::from_register(0b11 << 30), None);
+    /// ```
+    pub fn from_register(reg: u32) -> Option<Temperature> {
+        let unit = match reg >> 30 {
+            0 => Unit::Fahrenheit,
+            1 => Unit::Celsius,
+            2 => Unit::Kelvin,
+            _ => return None,
+        };
+
+        // shift the tag out, then back in with sign extension
+        let value = ((reg << 2) as i32) >> 2;
+
+        Some(Temperature::from((value as Float / 100.0, unit)))
+    }
+
+    /// Returns absolute zero in the given [Unit].
+    ///
+    /// That's -459.67° F, -273.15° C, or 0 K.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{Temperature, Unit};
+    /// #
+    /// let zero_c = Temperature::absolute_zero_in(Unit::Celsius);
+    /// assert_eq!(zero_c, Temperature::Celsius(-273.15));
+    /// ```
+    pub const fn absolute_zero_in(unit: Unit) -> Temperature {
+        match unit {
+            Unit::Fahrenheit => Temperature::Fahrenheit(-459.67),
+            Unit::Celsius => Temperature::Celsius(-273.15),
+            Unit::Kelvin => Temperature::Kelvin(0.0),
+        }
+    }
+
+    /// Returns absolute zero in `self`'s unit.
+    ///
+    /// Handy when you need to clamp something without caring which unit
+    /// you're in.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let room = Temperature::Fahrenheit(70.0);
+    /// assert_eq!(room.absolute_zero(), Temperature::Fahrenheit(-459.67));
+    /// ```
+    pub const fn absolute_zero(&self) -> Temperature {
+        Temperature::absolute_zero_in(self.unit())
+    }
+
+    /// Tells you if a [Temperature] is below absolute zero - an invalid state
+    /// for temperature.
+    ///
+    /// So... returns:
+    /// - `true` if `t` >= abs zero
+    /// - `false` if `t` < abs zero
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let temp = Temperature::Kelvin(0.0);
+    /// assert!(!temp.is_below_abs_zero());
+    ///
+    /// let temp2 = Temperature::Kelvin(-0.1);
+    /// assert!(temp2.is_below_abs_zero());
+    /// ```
+    pub fn is_below_abs_zero(&self) -> bool {
+        self.get_inner() < self.absolute_zero().get_inner()
+    }
+
+    /// Checks whether `self` is physically between `lower` and `upper`.
+    ///
+    /// Both bounds are *inclusive*, and they're converted into `self`'s unit
+    /// first, so they can be in any unit you like. If anything is `NaN`,
+    /// this returns `false`.
+    ///
+    /// For something that enforces bounds, see `CheckedTemperature`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let reading = Temperature::Celsius(21.0);
+    /// let comfy = (Temperature::Fahrenheit(68.0), Temperature::Fahrenheit(72.0));
+    ///
+    /// assert!(reading.is_within(comfy.0, comfy.1));
+    /// ```
+    pub fn is_within(&self, lower: Temperature, upper: Temperature) -> bool {
+        let unit = self.unit();
+        let value = self.get_inner();
+
+        lower.to_unit(unit).get_inner() <= value && value <= upper.to_unit(unit).get_inner()
+    }
+
+    /// Returns how far `self` is from `lower` to `upper`, as a fraction.
+    ///
+    /// This is `(self - lower) / (upper - lower)`, done in Kelvin, so `lower`
+    /// gives `0.0`, `upper` gives `1.0`, and halfway between gives `0.5`.
+    /// It's handy for drawing gauges. (It's the inverse of linear
+    /// interpolation.)
+    ///
+    /// Nothing is clamped! Temperatures outside the range give values below
+    /// `0.0` or above `1.0`, and swapping `lower` and `upper` flips the
+    /// direction. If `lower` and `upper` are physically equal, there's no
+    /// range to speak of, and this returns `0.0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let needle = Temperature::Celsius(50.0)
+    ///     .fraction_between(Temperature::Fahrenheit(32.0), Temperature::Kelvin(373.15));
+    ///
+    /// assert_approx_eq!(needle, 0.5);
+    /// ```
+    pub fn fraction_between(&self, lower: Temperature, upper: Temperature) -> Float {
+        let lower = lower.to_kelvin().into_inner();
+        let span = upper.to_kelvin().into_inner() - lower;
+
+        if span == 0.0 {
+            return 0.0;
+        }
+
+        (self.to_kelvin().into_inner() - lower) / span
+    }
+
+    /// Remaps `self` from a range of temperatures onto a range of plain
+    /// numbers, like a sensor's 0-50 °C onto a gauge's 0-100.
+    ///
+    /// This finds where `self` sits in `from` with
+    /// [Temperature::fraction_between] (in Kelvin), then lands at the same
+    /// spot in `to`. Unlike `fraction_between`, the result **is** clamped:
+    /// anything outside `from` sticks to the nearest end of `to`. If `from`
+    /// is empty (both ends physically equal), you get `to.0`. A `NaN`
+    /// temperature gives `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let sensor = (Temperature::Celsius(0.0), Temperature::Celsius(50.0));
+    /// let gauge = (0.0, 100.0);
+    ///
+    /// assert_approx_eq!(Temperature::Celsius(20.0).scale_to_range(sensor, gauge), 40.0);
+    /// assert_approx_eq!(Temperature::Celsius(80.0).scale_to_range(sensor, gauge), 100.0);
+    /// ```
+    pub fn scale_to_range(&self, from: (Temperature, Temperature), to: (Float, Float)) -> Float {
+        let fraction = self.fraction_between(from.0, from.1).clamp(0.0, 1.0);
+
+        to.0 + fraction * (to.1 - to.0)
+    }
+
+    /// Rounds `self` to the nearest step a sensor could actually report, like
+    /// the DS18B20's 1/16 °C.
+    ///
+    /// This makes two conversions: into Celsius to snap the value to the
+    /// nearest multiple of `step_celsius` (halves round away from zero), then
+    /// back into the original unit. The snapped value is exact in Celsius,
+    /// but the trip back to Fahrenheit or Kelvin can land a tiny float error
+    /// off a "clean" number, so compare those with a tolerance.
+    ///
+    /// If `step_celsius` isn't positive (or is `NaN`), or the value isn't
+    /// finite, you get `self` back unchanged. Same for values so huge that
+    /// they're already a whole number of steps.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let reading = Temperature::Celsius(21.04);
+    /// assert_eq!(reading.quantize_to_sensor(0.0625), Temperature::Celsius(21.0625));
+    /// ```
+    pub fn quantize_to_sensor(&self, step_celsius: Float) -> Temperature {
+        let steps = self.to_celsius().into_inner() / step_celsius;
+
+        // past this, every float is already a whole number
+        let whole = (1_u64 << Float::MANTISSA_DIGITS) as Float;
+
+        let usable = step_celsius > 0.0 && steps.abs() < whole;
+
+        if !usable {
+            return *self;
+        }
+
+        // `as` truncates toward zero, so nudge it first
+        let rounded = (steps + Float::copysign(0.5, steps)) as i64 as Float;
+
+        Temperature::Celsius(rounded * step_celsius).to_unit(self.unit())
+    }
+
+    /// Coerces `self` into a physically valid temperature.
+    ///
+    /// The rules are:
+    ///
+    /// - `NaN` becomes `Temperature::Kelvin(0.0)`.
+    /// - Anything below absolute zero becomes absolute zero, in the same unit.
+    /// - Positive infinity becomes `Float::MAX`, in the same unit.
+    /// - Everything else is left alone.
+    ///
+    /// This is mostly handy for fuzzing, where `arbitrary` will happily hand
+    /// you garbage.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let impossible = Temperature::Celsius(-300.0);
+    /// assert_eq!(impossible.sanitized(), Temperature::Celsius(-273.15));
+    ///
+    /// let garbage = Temperature::Fahrenheit(f64::NAN);
+    /// assert_eq!(garbage.sanitized(), Temperature::Kelvin(0.0));
+    /// ```
+    pub fn sanitized(self) -> Temperature {
+        if self.is_nan() {
+            return Temperature::Kelvin(0.0);
+        }
+
+        if self.is_below_abs_zero() {
+            return self.absolute_zero();
+        }
+
+        self.saturate()
+    }
+
+    /// Checks if the internal floating point number is `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let temp = Temperature::Fahrenheit(f64::NAN);
+    /// assert!(temp.is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        match self {
+            Temperature::Celsius(t) | Temperature::Fahrenheit(t) | Temperature::Kelvin(t) => {
+                t.is_nan()
+            }
+        }
+    }
+
+    /// Returns how much hotter `self` is than `other`, in Kelvin.
+    ///
+    /// This is the true difference, `self - other`, with both converted to
+    /// Kelvin first. It's negative when `self` is colder. Since a Kelvin is
+    /// the same size as a degree Celsius, it's also the difference in
+    /// Celsius; multiply by `1.8` for Fahrenheit degrees.
+    ///
+    /// That's different from the `-` operator! `a - b` gives back a
+    /// [Temperature] in `a`'s unit, which mixes up a *point* on a scale with
+    /// a *span* between two points. `Celsius(30.0) - Celsius(20.0)` is
+    /// `Celsius(10.0)`, which is 283.15 K if you convert it, not the 10 K
+    /// difference you probably wanted. This method always means the span.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let inside = Temperature::Celsius(21.0);
+    /// let outside = Temperature::Fahrenheit(50.0);
+    ///
+    /// assert_approx_eq!(inside.delta_kelvin(outside), 11.0);
+    /// ```
+    pub fn delta_kelvin(&self, other: Temperature) -> Float {
+        self.to_kelvin().into_inner() - other.to_kelvin().into_inner()
+    }
+
+    /// Returns the percent difference between `self` and `other`.
+    ///
+    /// Both temperatures are converted to Kelvin first, so the ratio is
+    /// physically meaningful. (Celsius and Fahrenheit cross zero, which makes
+    /// any percentage computed in them pretty silly!)
+    ///
+    /// The formula is `(|a - b| / ((a + b) / 2)) * 100`. If both temperatures
+    /// are exactly absolute zero, this returns `0.0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let sensor_a = Temperature::Kelvin(300.0);
+    /// let sensor_b = Temperature::Kelvin(301.0);
+    ///
+    /// // these sensors agree within 1%
+    /// assert!(sensor_a.percent_difference(sensor_b) < 1.0);
+    /// ```
+    pub fn percent_difference(&self, other: Temperature) -> Float {
+        let a = self.to_kelvin().into_inner();
+        let b = other.to_kelvin().into_inner();
+
+        // avoid dividing by zero when both are at absolute zero
+        if a == 0.0 && b == 0.0 {
+            return 0.0;
+        }
+
+        ((a - b).abs() / ((a + b) / 2.0)) * 100.0
+    }
+
+    /// Compares two temperatures physically (in Kelvin) with a *total*
+    /// order, so `NaN`s sort deterministically too.
+    ///
+    /// This uses the float type's `total_cmp`. Positive `NaN`s sort after
+    /// every other temperature, and negative `NaN`s sort before them. It's
+    /// perfect for `sort_by`!
+    ///
+    /// `total_cmp` would normally put `-0.0` before `0.0`, but they're the
+    /// same temperature, so they compare as equal here (see
+    /// [Temperature::canonicalize_zero]).
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let mut readings = [
+    ///     Temperature::Celsius(30.0),
+    ///     Temperature::Kelvin(f64::NAN),
+    ///     Temperature::Fahrenheit(50.0),
+    /// ];
+    /// readings.sort_by(Temperature::total_cmp_kelvin);
+    ///
+    /// assert_eq!(readings[0], Temperature::Fahrenheit(50.0));
+    /// assert!(readings[2].is_nan());
+    /// ```
+    pub fn total_cmp_kelvin(&self, other: &Temperature) -> core::cmp::Ordering {
+        let a = self.to_kelvin().canonicalize_zero().into_inner();
+        let b = other.to_kelvin().canonicalize_zero().into_inner();
+
+        a.total_cmp(&b)
+    }
+
+    /// Maps a float onto a number line where neighboring floats are
+    /// neighboring integers.
+    fn ordered_bits(x: Float) -> i64 {
+        #[cfg(not(feature = "f32"))]
+        let (bits, min) = (x.to_bits() as i64, i64::MIN);
+
+        #[cfg(feature = "f32")]
+        let (bits, min) = (x.to_bits() as i32 as i64, i32::MIN as i64);
+
+        // floats are sign-magnitude, so flip the negative ones around
+        if bits < 0 {
+            min - bits
+        } else {
+            bits
+        }
+    }
+
+    /// Checks if `self` and `other` are within `ulps` representable floats
+    /// of each other, in Kelvin.
+    ///
+    /// An ULP ("unit in the last place") is the gap between one float and the
+    /// next. That gap grows with the number, so this scales naturally: a
+    /// couple of ULPs is a tiny fraction of a degree at room temperature,
+    /// and a bit more on the surface of the sun. It's a lot more robust than
+    /// picking a single epsilon.
+    ///
+    /// Some edge cases:
+    ///
+    /// - `NaN` is never close to anything, not even itself.
+    /// - `0.0` and `-0.0` are the same (zero ULPs apart).
+    /// - Tiny values on opposite sides of zero can still be close, since the
+    ///   ULPs are counted straight across zero.
+    /// - Infinity is one ULP away from the largest finite value.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let a = Temperature::Kelvin(0.1 + 0.2);
+    /// let b = Temperature::Kelvin(0.3);
+    ///
+    /// assert_ne!(a, b); // floats, am i right?
+    /// assert!(a.is_close_to(b, 1));
+    /// ```
+    pub fn is_close_to(&self, other: Temperature, ulps: u32) -> bool {
+        let a = self.to_kelvin().into_inner();
+        let b = other.to_kelvin().into_inner();
+
+        if a.is_nan() || b.is_nan() {
+            return false;
+        }
+
+        let distance = Temperature::ordered_bits(a).abs_diff(Temperature::ordered_bits(b));
+        distance <= ulps as u64
+    }
+
+    /// Returns `Some(self)` if the inner value is finite, or `None` if it's
+    /// infinite or `NaN`.
+    fn finite(self) -> Option<Temperature> {
+        self.get_inner().is_finite().then_some(self)
+    }
+
+    /// Adds `rhs` to `self`, returning `None` if the result isn't finite.
+    ///
+    /// This works just like `+`, so the result is in `self`'s unit. It's a
+    /// lightweight alternative to [CheckedTemperature](crate::checked) when
+    /// you only care about overflow.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let warm = Temperature::Celsius(20.0).checked_add(Temperature::Celsius(5.0));
+    /// assert_eq!(warm, Some(Temperature::Celsius(25.0)));
+    ///
+    /// let huge = Temperature::Kelvin(f64::MAX);
+    /// assert_eq!(huge.checked_add(huge), None);
+    /// ```
+    pub fn checked_add(self, rhs: Temperature) -> Option<Temperature> {
+        (self + rhs).finite()
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if the result isn't
+    /// finite.
+    ///
+    /// This works just like `-`, so the result is in `self`'s unit.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let cool = Temperature::Celsius(20.0).checked_sub(Temperature::Celsius(5.0));
+    /// assert_eq!(cool, Some(Temperature::Celsius(15.0)));
+    ///
+    /// let tiny = Temperature::Kelvin(f64::MIN);
+    /// assert_eq!(tiny.checked_sub(Temperature::Kelvin(f64::MAX)), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Temperature) -> Option<Temperature> {
+        (self - rhs).finite()
+    }
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result isn't
+    /// finite.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let doubled = Temperature::Kelvin(150.0).checked_mul(2.0);
+    /// assert_eq!(doubled, Some(Temperature::Kelvin(300.0)));
+    ///
+    /// assert_eq!(Temperature::Kelvin(f64::MAX).checked_mul(2.0), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Float) -> Option<Temperature> {
+        (self * rhs).finite()
+    }
+
+    /// Divides `self` by `rhs`, returning `None` if `rhs` is zero or the
+    /// result isn't finite.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let halved = Temperature::Kelvin(300.0).checked_div(2.0);
+    /// assert_eq!(halved, Some(Temperature::Kelvin(150.0)));
+    ///
+    /// assert_eq!(Temperature::Kelvin(300.0).checked_div(0.0), None);
+    /// ```
+    pub fn checked_div(self, rhs: Float) -> Option<Temperature> {
+        if rhs == 0.0 {
+            return None;
+        }
+
+        (self / rhs).finite()
+    }
+}
+
+/// Returns the colder of two temperatures, keeping its original unit.
+///
+/// Temperatures are compared physically, in Kelvin, so `min` works across
+/// units. (The derived `PartialOrd` on [Temperature] doesn't!)
+///
+/// If one of them is `NaN`, you get the other one back. If they're
+/// physically equal, you get `a`.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::Temperature;
+/// #
+/// let colder = simmer::min(Temperature::Celsius(0.0), Temperature::Fahrenheit(31.0));
+/// assert_eq!(colder, Temperature::Fahrenheit(31.0));
+/// ```
+pub fn min(a: Temperature, b: Temperature) -> Temperature {
+    if b.is_nan() || (!a.is_nan() && a.to_kelvin().into_inner() <= b.to_kelvin().into_inner()) {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the warmer of two temperatures, keeping its original unit.
+///
+/// Temperatures are compared physically, in Kelvin, so `max` works across
+/// units. (The derived `PartialOrd` on [Temperature] doesn't!)
+///
+/// If one of them is `NaN`, you get the other one back. If they're
+/// physically equal, you get `a`.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::Temperature;
+/// #
+/// let warmer = simmer::max(Temperature::Kelvin(300.0), Temperature::Celsius(20.0));
+/// assert_eq!(warmer, Temperature::Kelvin(300.0));
+/// ```
+pub fn max(a: Temperature, b: Temperature) -> Temperature {
+    if b.is_nan() || (!a.is_nan() && a.to_kelvin().into_inner() >= b.to_kelvin().into_inner()) {
+        a
+    } else {
+        b
+    }
+}
+
+/// How far off a conversion in [self_test] can be before it fails.
+#[cfg(not(feature = "f32"))]
+const SELF_TEST_TOLERANCE: Float = 1e-9;
+
+/// How far off a conversion in [self_test] can be before it fails.
+#[cfg(feature = "f32")]
+const SELF_TEST_TOLERANCE: Float = 1e-3;
+
+/// Checks that the conversion math works on this device.
+///
+/// This converts a few well-known temperatures (the freezing and boiling
+/// points of water, plus absolute zero) between every unit and back again,
+/// making sure each one lands within a small tolerance. The tolerance is
+/// looser when the `f32` feature is on.
+///
+/// It's meant for field diagnostics. If a miscompilation or a broken FPU
+/// ever corrupts the math, you'll get an `Err` naming the first check that
+/// failed, which you can log.
+///
+/// # Usage
+///
+/// ```
+/// if let Err(check) = simmer::self_test() {
+///     panic!("temperature self-test failed: {check}");
+/// }
+/// ```
+pub fn self_test() -> Result<(), &'static str> {
+    fn check(actual: Temperature, expected: Float, name: &'static str) -> Result<(), &'static str> {
+        let actual = actual.into_inner();
+
+        // written this way so `NaN` fails, too
+        if (actual - expected).abs() <= SELF_TEST_TOLERANCE {
+            Ok(())
+        } else {
+            Err(name)
+        }
+    }
+
+    let freezing = Temperature::Celsius(0.0);
+    check(
+        freezing.to_fahrenheit(),
+        32.0,
+        "freezing point: Celsius to Fahrenheit",
+    )?;
+    check(
+        freezing.to_kelvin(),
+        273.15,
+        "freezing point: Celsius to Kelvin",
+    )?;
+    check(
+        freezing.to_fahrenheit().to_celsius(),
+        0.0,
+        "freezing point: Fahrenheit round trip",
+    )?;
+    check(
+        freezing.to_kelvin().to_celsius(),
+        0.0,
+        "freezing point: Kelvin round trip",
+    )?;
+
+    let boiling = Temperature::Celsius(100.0);
+    check(
+        boiling.to_fahrenheit(),
+        212.0,
+        "boiling point: Celsius to Fahrenheit",
+    )?;
+    check(
+        boiling.to_kelvin(),
+        373.15,
+        "boiling point: Celsius to Kelvin",
+    )?;
+    check(
+        boiling.to_fahrenheit().to_celsius(),
+        100.0,
+        "boiling point: Fahrenheit round trip",
+    )?;
+    check(
+        boiling.to_kelvin().to_celsius(),
+        100.0,
+        "boiling point: Kelvin round trip",
+    )?;
+
+    let absolute_zero = Temperature::Kelvin(0.0);
+    check(
+        absolute_zero.to_celsius(),
+        -273.15,
+        "absolute zero: Kelvin to Celsius",
+    )?;
+    check(
+        absolute_zero.to_fahrenheit(),
+        -459.67,
+        "absolute zero: Kelvin to Fahrenheit",
+    )?;
+    check(
+        absolute_zero.to_celsius().to_kelvin(),
+        0.0,
+        "absolute zero: Celsius round trip",
+    )?;
+    check(
+        absolute_zero.to_fahrenheit().to_kelvin(),
+        0.0,
+        "absolute zero: Fahrenheit round trip",
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Float> for Temperature {
+    fn into(self) -> Float {
+        match self {
+            Temperature::Fahrenheit(f) => f,
+            Temperature::Celsius(c) => c,
+            Temperature::Kelvin(k) => k,
+        }
+    }
+}
+
+impl From<(Float, Unit)> for Temperature {
+    /// Creates a [Temperature] from a value and its [Unit], without
+    /// converting anything.
+    fn from((value, unit): (Float, Unit)) -> Self {
+        match unit {
+            Unit::Fahrenheit => Temperature::Fahrenheit(value),
+            Unit::Celsius => Temperature::Celsius(value),
+            Unit::Kelvin => Temperature::Kelvin(value),
+        }
+    }
+}
+
+/// Characters that sit between a number and its unit, but don't mean
+/// anything on their own.
+///
+/// That's the usual degree sign, plus the two lookalikes people type by
+/// accident: the masculine ordinal (`º`) and the ring above (`˚`).
+const DEGREE_SIGNS: [char; 3] = ['°', 'º', '˚'];
+
+impl core::str::FromStr for Temperature {
+    type Err = ParseTemperatureError;
+
+    /// Parses text like `"25 °C"`, `"98.6°F"`, or `"273.15 K"`.
+    ///
+    /// It's meant for text pasted from anywhere, so it's forgiving:
+    ///
+    /// - Any Unicode whitespace (including non-breaking spaces) is allowed
+    ///   around the number and the unit.
+    /// - Degree signs (`°`, and lookalikes `º` and `˚`) before the unit are
+    ///   skipped.
+    /// - The unit can be a letter (`C`, `F`, `K`) or a full name (`celsius`,
+    ///   `fahrenheit`, `kelvin`), in any case. The single-character `℃` and
+    ///   `℉` work too, and so does the Kelvin sign (`U+212A`).
+    ///
+    /// Errors are a [ParseTemperatureError]: a missing number or unit is
+    /// [ParseTemperatureError::MissingField], a number that doesn't parse is
+    /// [ParseTemperatureError::InvalidNumber], and anything else after the
+    /// number is [ParseTemperatureError::UnknownUnit].
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// assert_eq!("25 °C".parse(), Ok(Temperature::Celsius(25.0)));
+    /// assert_eq!("98.6°f".parse(), Ok(Temperature::Fahrenheit(98.6)));
+    /// assert_eq!("273.15\u{a0}Kelvin".parse(), Ok(Temperature::Kelvin(273.15)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // the number is everything up to the first character that can't be
+        // part of one. no unit starts with an `e`, so exponents are fine
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E')))
+            .unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+
+        let unit = unit
+            .trim_start_matches(|c: char| c.is_whitespace() || DEGREE_SIGNS.contains(&c))
+            .trim_end();
+
+        if number.is_empty() {
+            return Err(ParseTemperatureError::MissingField("value"));
+        }
+
+        if unit.is_empty() {
+            return Err(ParseTemperatureError::MissingField("unit"));
+        }
+
+        let value = number
+            .parse::<Float>()
+            .map_err(|_| ParseTemperatureError::InvalidNumber)?;
+
+        let unit = match unit {
+            "℉" => Unit::Fahrenheit,
+            "℃" => Unit::Celsius,
+            "\u{212a}" => Unit::Kelvin,
+            _ if unit.eq_ignore_ascii_case("f") || unit.eq_ignore_ascii_case("fahrenheit") => {
+                Unit::Fahrenheit
+            }
+            _ if unit.eq_ignore_ascii_case("c") || unit.eq_ignore_ascii_case("celsius") => {
+                Unit::Celsius
+            }
+            _ if unit.eq_ignore_ascii_case("k") || unit.eq_ignore_ascii_case("kelvin") => {
+                Unit::Kelvin
+            }
+            _ => return Err(ParseTemperatureError::UnknownUnit),
+        };
+
+        Ok(Temperature::from((value, unit)))
+    }
+}
+
+// various display impls
+
+impl core::fmt::Display for Unit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let unit = match self {
+            Unit::Fahrenheit => "Fahrenheit",
+            Unit::Celsius => "Celsius",
+            Unit::Kelvin => "Kelvin",
+        };
+
+        write!(f, "{unit}")
+    }
+}
+
+impl core::fmt::Display for Temperature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_inner())
+    }
+}
+
+impl ufmt::uDebug for Temperature {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        let unit = match self {
+            Temperature::Fahrenheit(_) => "Fahrenheit",
+            Temperature::Celsius(_) => "Celsius",
+            Temperature::Kelvin(_) => "Kelvin",
+        };
+
+        #[cfg(feature = "f32")]
+        return ufmt::uwrite!(
+            f,
+            "Temperature::{}({})",
+            unit,
+            ufmt_float::uFmt_f32::Five(self.get_inner())
+        );
+
+        #[cfg(not(feature = "f32"))]
+        return ufmt::uwrite!(
+            f,
+            "Temperature::{}({})",
+            unit,
+            ufmt_float::uFmt_f64::Five(self.get_inner())
+        );
+    }
+}
+
+impl ufmt::uDisplay for Temperature {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        #[cfg(feature = "f32")]
+        return ufmt::uwrite!(f, "{}", ufmt_float::uFmt_f32::Five(self.get_inner()));
+
+        #[cfg(not(feature = "f32"))]
+        return ufmt::uwrite!(f, "{}", ufmt_float::uFmt_f64::Five(self.get_inner()));
+    }
+}
+
+// operator overloading impls
+
+/// **Warning**: this compares the raw inner value *only*. No conversion
+/// happens, so `Temperature::Celsius(100.0) == 100.0` and
+/// `Temperature::Fahrenheit(100.0) == 100.0` are both true!
+impl PartialEq<Float> for Temperature {
+    fn eq(&self, other: &Float) -> bool {
+        self.get_inner() == *other
+    }
+}
+
+/// **Warning**: this compares the raw inner value *only*. No conversion
+/// happens, so `Temperature::Kelvin(50.0) > 40.0` is true even though that's
+/// colder than `Temperature::Celsius(40.0)`.
+///
+/// It's meant for quick threshold checks where you already know the unit:
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::Temperature;
+/// #
+/// let water = Temperature::Celsius(101.0);
+///
+/// if water > 100.0 {
+///     println!("it's boiling!");
+/// }
+/// ```
+impl PartialOrd<Float> for Temperature {
+    fn partial_cmp(&self, other: &Float) -> Option<core::cmp::Ordering> {
+        self.get_inner().partial_cmp(other)
+    }
+}
 
 impl core::ops::Add for Temperature {
     type Output = Self;
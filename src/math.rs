@@ -0,0 +1,132 @@
+//! # Math
+//!
+//! A few float functions that `core` doesn't have.
+//!
+//! Without `std`, there's no `exp` and friends. These are small, portable
+//! versions that are accurate enough for temperature work on both `f32` and
+//! `f64`.
+
+use crate::Float;
+
+#[cfg(not(feature = "f32"))]
+use core::f64::consts::{FRAC_PI_2, LN_2};
+
+#[cfg(feature = "f32")]
+use core::f32::consts::{FRAC_PI_2, LN_2};
+
+/// Halving the bits of a float (plus this) roughly halves its exponent,
+/// which is a decent first guess for a square root.
+#[cfg(not(feature = "f32"))]
+const SQRT_MAGIC: u64 = 0x1ff8_0000_0000_0000;
+
+#[cfg(feature = "f32")]
+const SQRT_MAGIC: u32 = 0x1fc0_0000;
+
+/// Anything above this overflows `exp` to infinity.
+const EXP_MAX: Float = Float::MAX_EXP as Float * LN_2;
+
+/// Anything below this underflows `exp` to zero.
+const EXP_MIN: Float = (Float::MIN_EXP - Float::MANTISSA_DIGITS as i32 - 1) as Float * LN_2;
+
+/// Returns `2^n`, built up by squaring so it stays exact.
+fn pow2(n: i32) -> Float {
+    let mut base: Float = if n < 0 { 0.5 } else { 2.0 };
+    let mut remaining = n.unsigned_abs();
+    let mut result = 1.0;
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= base;
+        }
+
+        base *= base;
+        remaining >>= 1;
+    }
+
+    result
+}
+
+/// Returns `e^x`.
+///
+/// This splits `x` into `n * ln(2) + r` with a small `r`, sums the Taylor
+/// series for `e^r`, then scales the result by `2^n` (in two steps, so
+/// results near the float's limits, including subnormals, still come out).
+pub(crate) fn exp(x: Float) -> Float {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x > EXP_MAX {
+        return Float::INFINITY;
+    }
+
+    if x < EXP_MIN {
+        return 0.0;
+    }
+
+    let scaled = x / LN_2;
+    let n = (scaled + Float::copysign(0.5, scaled)) as i32;
+    let r = x - n as Float * LN_2;
+
+    // |r| <= ln(2) / 2, so 14 terms is plenty, even for `f64`
+    let mut sum = 1.0;
+    for i in (1..=14).rev() {
+        sum = 1.0 + sum * r / i as Float;
+    }
+
+    // `2^n` alone can overflow (or flush to zero) right at the edges, even
+    // when the result fits. so scale in two halves
+    let half = n / 2;
+    sum * pow2(n - half) * pow2(half)
+}
+
+/// Returns the square root of `x`.
+///
+/// This starts from a guess made by halving the exponent bits, then polishes
+/// it with a few rounds of Newton's method.
+pub(crate) fn sqrt(x: Float) -> Float {
+    if x.is_nan() || x < 0.0 {
+        return Float::NAN;
+    }
+
+    if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+
+    let mut guess = Float::from_bits((x.to_bits() >> 1) + SQRT_MAGIC);
+
+    // each round doubles the correct digits, starting from about one
+    for _ in 0..6 {
+        guess = 0.5 * (guess + x / guess);
+    }
+
+    guess
+}
+
+/// Returns the arctangent of `x`, in radians.
+///
+/// Big inputs are flipped with `atan(x) = π/2 - atan(1/x)`, then the
+/// half-angle formula shrinks what's left until the Taylor series converges
+/// quickly.
+pub(crate) fn atan(x: Float) -> Float {
+    if x.abs() > 1.0 {
+        return Float::copysign(FRAC_PI_2, x) - atan(1.0 / x);
+    }
+
+    // |x| <= 1 here, and two halvings bring it under 0.2
+    let mut reduced = x;
+    for _ in 0..2 {
+        reduced /= 1.0 + sqrt(1.0 + reduced * reduced);
+    }
+
+    let squared = reduced * reduced;
+    let mut sum = 0.0;
+    for i in (0..14).rev() {
+        let term = 1.0 / (2 * i + 1) as Float;
+        let term = if i % 2 == 0 { term } else { -term };
+
+        sum = term + squared * sum;
+    }
+
+    4.0 * reduced * sum
+}
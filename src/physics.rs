@@ -0,0 +1,495 @@
+//! # Physics
+//!
+//! A few small helpers for doing physics with [Temperature]s.
+//!
+//! Everything in here works with *true* temperature differences. That means
+//! temperatures are converted to Kelvin before any math happens, so you won't
+//! get strange results from scales that cross zero.
+
+use crate::{convert, math, Float, Temperature};
+
+/// The Stefan–Boltzmann constant, σ, in W·m⁻²·K⁻⁴.
+#[allow(clippy::excessive_precision)] // it's just truncated on `f32`
+pub const STEFAN_BOLTZMANN: Float = 5.670_374_419e-8;
+
+/// Wien's displacement constant, `b`, in m·K.
+#[allow(clippy::excessive_precision)] // it's just truncated on `f32`
+pub const WIEN_DISPLACEMENT: Float = 2.897_771_955e-3;
+
+/// A temperature gradient between two points some distance apart.
+///
+/// You can make one with the [gradient] function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient {
+    hot: Temperature,
+    cold: Temperature,
+    distance_m: Float,
+}
+
+/// Creates a [Gradient] from a `hot` side, a `cold` side, and the distance
+/// between them in meters.
+///
+/// Negative distances are treated as their absolute value.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{physics::gradient, Temperature};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// // a 2 meter wall, 20 C inside and 0 C outside
+/// let wall = gradient(Temperature::Celsius(20.0), Temperature::Celsius(0.0), 2.0);
+/// assert_approx_eq!(wall.rate_per_meter(), 10.0);
+/// ```
+pub fn gradient(hot: Temperature, cold: Temperature, distance_m: Float) -> Gradient {
+    Gradient {
+        hot,
+        cold,
+        distance_m: distance_m.abs(),
+    }
+}
+
+impl Gradient {
+    /// The temperature at the hot side.
+    pub fn hot(&self) -> Temperature {
+        self.hot
+    }
+
+    /// The temperature at the cold side.
+    pub fn cold(&self) -> Temperature {
+        self.cold
+    }
+
+    /// The distance between both sides, in meters.
+    pub fn distance(&self) -> Float {
+        self.distance_m
+    }
+
+    /// Returns the rate of change in Kelvin per meter.
+    ///
+    /// The sign convention is `hot - cold`. So, when `hot` really is hotter
+    /// than `cold`, the rate is positive. If you mixed them up, it's negative!
+    ///
+    /// When the distance is zero, there's no meaningful rate. You'll get
+    /// `0.0` if both sides are the same temperature, or an infinity (with the
+    /// sign above) otherwise.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{physics::gradient, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a 1.8 F difference is the same as a 1 K difference
+    /// let g = gradient(Temperature::Fahrenheit(33.8), Temperature::Fahrenheit(32.0), 0.5);
+    /// assert_approx_eq!(g.rate_per_meter(), 2.0);
+    /// ```
+    pub fn rate_per_meter(&self) -> Float {
+        let delta = self.hot.to_kelvin().into_inner() - self.cold.to_kelvin().into_inner();
+
+        if self.distance_m == 0.0 {
+            if delta == 0.0 {
+                return 0.0;
+            }
+
+            return Float::INFINITY.copysign(delta);
+        }
+
+        delta / self.distance_m
+    }
+}
+
+impl Temperature {
+    /// Estimates the steady-state temperature of a simple heater from its PWM
+    /// duty cycle.
+    ///
+    /// This is a linear model: `ambient + duty * max_rise`. `max_rise` is a
+    /// temperature *difference* (how much hotter than ambient the heater gets
+    /// at 100% duty), so only its scale is converted, not its offset. For
+    /// example, `Fahrenheit(18.0)` is a rise of 10 degrees Celsius.
+    ///
+    /// `duty` is clamped to `0.0..=1.0`, and a `NaN` duty is treated as
+    /// `0.0` (off). The result is in `ambient`'s unit.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let ambient = Temperature::Celsius(20.0);
+    /// let max_rise = Temperature::Celsius(80.0);
+    ///
+    /// let half = Temperature::from_duty_cycle(0.5, ambient, max_rise);
+    /// assert_approx_eq!(half.into_inner(), 60.0);
+    /// ```
+    pub fn from_duty_cycle(
+        duty: Float,
+        ambient: Temperature,
+        max_rise: Temperature,
+    ) -> Temperature {
+        let duty = if duty.is_nan() {
+            0.0
+        } else {
+            duty.clamp(0.0, 1.0)
+        };
+        let (scale, _) = convert::linear_coeffs(max_rise.unit(), ambient.unit());
+        let rise = max_rise.get_inner() * scale;
+
+        Temperature::from((ambient.get_inner() + duty * rise, ambient.unit()))
+    }
+
+    /// Finds the equilibrium temperature after mixing two masses together.
+    ///
+    /// Each side is `(temperature, mass_kg, specific_heat)`, with the
+    /// specific heat in J/(kg·K). Assuming no heat escapes (and nothing
+    /// changes phase), the result is
+    /// `(m₁c₁T₁ + m₂c₂T₂) / (m₁c₁ + m₂c₂)` with `T` in Kelvin. It comes back
+    /// in `a`'s unit.
+    ///
+    /// If neither side can hold any heat (the total heat capacity is zero),
+    /// nothing happens, and you get `a`'s temperature back.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // dropping a hot 1 kg iron block (450 J/kg·K) into 5 kg of water
+    /// let iron = (Temperature::Celsius(500.0), 1.0, 450.0);
+    /// let water = (Temperature::Celsius(20.0), 5.0, 4186.0);
+    ///
+    /// let result = Temperature::mix(water, iron);
+    /// assert_approx_eq!(result.into_inner(), 30.1, 0.1);
+    /// ```
+    pub fn mix(a: (Temperature, Float, Float), b: (Temperature, Float, Float)) -> Temperature {
+        let (t_a, m_a, c_a) = a;
+        let (t_b, m_b, c_b) = b;
+
+        let capacity_a = m_a * c_a;
+        let capacity_b = m_b * c_b;
+        let total = capacity_a + capacity_b;
+
+        if total == 0.0 {
+            return t_a;
+        }
+
+        let k = (capacity_a * t_a.to_kelvin().into_inner()
+            + capacity_b * t_b.to_kelvin().into_inner())
+            / total;
+
+        Temperature::Kelvin(k).to_unit(t_a.unit())
+    }
+
+    /// Works out how hot (or cold) some added water needs to be so that the
+    /// mix ends up at `desired`.
+    ///
+    /// This is [Temperature::mix] solved for the unknown input temperature,
+    /// assuming both sides are the same substance (so specific heat cancels
+    /// out). The math is done in Kelvin, and the result comes back in
+    /// `current`'s unit.
+    ///
+    /// Adding nothing can't change anything, so a zero `input_mass` just
+    /// gives you `desired` back. Also, some targets are impossible! The result
+    /// isn't clamped, so check it with [Temperature::is_below_abs_zero] (or
+    /// against your boiling point) if that matters to you.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a 10 kg tank at 20 C - what should 2 kg of added water be to hit 30 C?
+    /// let input = Temperature::required_input_temp(
+    ///     Temperature::Celsius(20.0),
+    ///     10.0,
+    ///     2.0,
+    ///     Temperature::Celsius(30.0),
+    /// );
+    ///
+    /// assert_approx_eq!(input.into_inner(), 80.0);
+    /// ```
+    pub fn required_input_temp(
+        current: Temperature,
+        current_mass: Float,
+        input_mass: Float,
+        desired: Temperature,
+    ) -> Temperature {
+        if input_mass == 0.0 {
+            return desired;
+        }
+
+        let k_current = current.to_kelvin().into_inner();
+        let k_desired = desired.to_kelvin().into_inner();
+        let k = (k_desired * (current_mass + input_mass) - k_current * current_mass) / input_mass;
+
+        Temperature::Kelvin(k).to_unit(current.unit())
+    }
+
+    /// Returns an iterator over a cooling (or heating) curve, following
+    /// Newton's law of cooling.
+    ///
+    /// Each item is `step_secs` seconds after the last, starting with `start`
+    /// itself at `t = 0`. The temperature approaches `ambient` exponentially:
+    /// `T(t) = ambient + (start - ambient) * e^(-k * t)`, where `k` is the
+    /// cooling constant (per second). The math is done in Kelvin, and every
+    /// item is in `start`'s unit.
+    ///
+    /// **This iterator never ends!** Use `take`, `take_while`, or similar
+    /// to stop it.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a cup of coffee on the desk, checked every minute
+    /// let coffee = Temperature::cooling_curve(
+    ///     Temperature::Celsius(90.0),
+    ///     Temperature::Celsius(20.0),
+    ///     0.001,
+    ///     60.0,
+    /// );
+    ///
+    /// let after_ten_minutes = coffee.skip(10).next().unwrap();
+    /// assert_approx_eq!(after_ten_minutes.into_inner(), 58.42, 0.01);
+    /// ```
+    pub fn cooling_curve(
+        start: Temperature,
+        ambient: Temperature,
+        k: Float,
+        step_secs: Float,
+    ) -> impl Iterator<Item = Temperature> {
+        let unit = start.unit();
+        let ambient_k = ambient.to_kelvin().into_inner();
+        let decay = math::exp(-k * step_secs);
+
+        let first = start.to_kelvin().into_inner() - ambient_k;
+
+        core::iter::successors(Some(first), move |delta| Some(delta * decay))
+            .map(move |delta| Temperature::Kelvin(ambient_k + delta).to_unit(unit))
+    }
+
+    /// Estimates the absolute humidity of `air`, in grams of water vapor per
+    /// cubic meter (g/m³).
+    ///
+    /// `relative_humidity` is a percentage, and it's clamped to `0.0..=100.0`.
+    /// The math is done in Celsius (`T`), using the Magnus-Tetens (Bolton)
+    /// formula for the saturation vapor pressure in hPa:
+    ///
+    /// `e_s = 6.112 * e^(17.67 * T / (T + 243.5))`
+    ///
+    /// Then, using the ideal gas law for water vapor:
+    ///
+    /// `AH = e_s * RH * 2.1674 / (T + 273.15)`
+    ///
+    /// This is accurate to about 0.1% between -30 °C and 35 °C, which covers
+    /// pretty much any weather. A `NaN` anywhere gives you `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a warm, muggy afternoon
+    /// let ah = Temperature::absolute_humidity(Temperature::Fahrenheit(86.0), 70.0);
+    /// assert_approx_eq!(ah, 21.2, 0.1);
+    /// ```
+    pub fn absolute_humidity(air: Temperature, relative_humidity: Float) -> Float {
+        let c = air.to_celsius().into_inner();
+        let rh = relative_humidity.clamp(0.0, 100.0);
+
+        let saturation_hpa = 6.112 * math::exp(17.67 * c / (c + 243.5));
+
+        saturation_hpa * rh * 2.1674 / (c + 273.15)
+    }
+
+    /// Estimates the wet-bulb temperature of `air`: how cold a wet
+    /// thermometer gets from evaporation alone.
+    ///
+    /// `relative_humidity` is a percentage. This uses Stull's (2011) empirical
+    /// fit, computed in Celsius (`T`) with `RH` as a percentage:
+    ///
+    /// `Tw = T * atan(0.151977 * (RH + 8.313659)^0.5) + atan(T + RH)
+    ///       - atan(RH - 1.676331) + 0.00391838 * RH^1.5 * atan(0.023101 * RH)
+    ///       - 4.686035`
+    ///
+    /// The fit was made at sea-level pressure, and it's within about 0.3 °C
+    /// for air between -20 °C and 50 °C with 5% to 99% humidity. Humidity is
+    /// clamped to that `5.0..=99.0` range, since the formula goes haywire
+    /// outside it. Temperatures aren't clamped, but don't trust the result
+    /// much past those limits.
+    ///
+    /// The result comes back in the same unit as `air`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // the worked example from Stull's paper
+    /// let wet = Temperature::wet_bulb(Temperature::Celsius(20.0), 50.0);
+    /// assert_approx_eq!(wet.into_inner(), 13.7, 0.01);
+    /// ```
+    pub fn wet_bulb(air: Temperature, relative_humidity: Float) -> Temperature {
+        let t = air.to_celsius().into_inner();
+        let rh = relative_humidity.clamp(5.0, 99.0);
+
+        let wet = t * math::atan(0.151977 * math::sqrt(rh + 8.313659)) + math::atan(t + rh)
+            - math::atan(rh - 1.676331)
+            + 0.00391838 * rh * math::sqrt(rh) * math::atan(0.023101 * rh)
+            - 4.686035;
+
+        Temperature::Celsius(wet).to_unit(air.unit())
+    }
+
+    /// Returns how far a part with the given temperature coefficient drifts
+    /// at this temperature, in parts per million (ppm).
+    ///
+    /// This is `tempco_ppm_per_c * delta`, where `delta` is the true
+    /// temperature difference between `self` and `reference` in degrees
+    /// Celsius (or Kelvin - they're the same size). Warmer than `reference`
+    /// gives a drift with the same sign as the tempco.
+    ///
+    /// If both temperatures are physically equal, the drift is always `0.0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a crystal calibrated at 25 C, drifting -0.5 ppm per degree
+    /// let reference = Temperature::Celsius(25.0);
+    /// let hot = Temperature::Celsius(45.0);
+    ///
+    /// assert_approx_eq!(hot.drift_ppm(reference, -0.5), -10.0);
+    /// ```
+    pub fn drift_ppm(&self, reference: Temperature, tempco_ppm_per_c: Float) -> Float {
+        let delta = self.to_kelvin().into_inner() - reference.to_kelvin().into_inner();
+
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        tempco_ppm_per_c * delta
+    }
+
+    /// Returns how much a part grows (or shrinks) from thermal expansion, in
+    /// meters.
+    ///
+    /// This is linear expansion, `L₀ * α * ΔT`, where `ΔT` is the true
+    /// temperature difference between `self` and `reference` in Kelvin, and
+    /// `coeff_per_k` is the material's expansion coefficient, α (per
+    /// Kelvin). Warmer than `reference` gives a positive change for normal
+    /// materials.
+    ///
+    /// If both temperatures are physically equal, the change is always
+    /// `0.0`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // a 10 m aluminum rail (23 ppm/K) on a hot day
+    /// let summer = Temperature::Celsius(45.0);
+    /// let growth = summer.length_change(Temperature::Celsius(20.0), 10.0, 23e-6);
+    ///
+    /// assert_approx_eq!(growth, 0.00575); // almost 6 mm!
+    /// ```
+    pub fn length_change(
+        &self,
+        reference: Temperature,
+        original_length_m: Float,
+        coeff_per_k: Float,
+    ) -> Float {
+        let delta = self.to_kelvin().into_inner() - reference.to_kelvin().into_inner();
+
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        original_length_m * coeff_per_k * delta
+    }
+
+    /// Returns the power radiated per unit area by a perfect blackbody at
+    /// this temperature, in watts per square meter (W/m²).
+    ///
+    /// This is the Stefan–Boltzmann law, `σT⁴`, where `T` is in Kelvin and
+    /// `σ` is [STEFAN_BOLTZMANN].
+    ///
+    /// Temperatures below absolute zero can't radiate anything, so they're
+    /// clamped to absolute zero (and you'll get back `0.0`). A `NaN`
+    /// temperature gives `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// // the sun puts out about 63 MW for every square meter of its surface
+    /// let sun = Temperature::Kelvin(5778.0);
+    /// let power = sun.blackbody_power();
+    ///
+    /// assert!((power - 6.32e7).abs() < 0.01e7);
+    /// ```
+    pub fn blackbody_power(&self) -> Float {
+        let mut k = self.to_kelvin().into_inner();
+
+        // `max` would turn `NaN` into zero, so only clamp real negatives
+        if k < 0.0 {
+            k = 0.0;
+        }
+
+        STEFAN_BOLTZMANN * k * k * k * k
+    }
+
+    /// Returns the wavelength, in meters, at which a blackbody at this
+    /// temperature glows the brightest.
+    ///
+    /// This is Wien's displacement law, `b / T`, where `T` is in Kelvin and `b`
+    /// is [WIEN_DISPLACEMENT].
+    ///
+    /// At (or below) absolute zero, nothing peaks anywhere, so this returns
+    /// infinity.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// // people glow in the infrared, at around 9.4 micrometers
+    /// let body = Temperature::Celsius(37.0);
+    /// let peak = body.peak_wavelength();
+    ///
+    /// assert!((peak - 9.34e-6).abs() < 0.01e-6);
+    /// ```
+    pub fn peak_wavelength(&self) -> Float {
+        let k = self.to_kelvin().into_inner();
+
+        if k <= 0.0 {
+            return Float::INFINITY;
+        }
+
+        WIEN_DISPLACEMENT / k
+    }
+}
@@ -0,0 +1,142 @@
+//! # Reference
+//!
+//! A handful of famous temperatures that most people have a feel for.
+//!
+//! These are great for putting a number in context. (Is 300 K hot? It's
+//! pretty close to room temperature!)
+
+use crate::{Float, Temperature};
+
+/// Well-known reference temperatures, along with their names, from coldest
+/// to hottest.
+pub const REFERENCE_POINTS: [(&str, Temperature); 5] = [
+    ("absolute zero", Temperature::Kelvin(0.0)),
+    ("freezing point of water", Temperature::Celsius(0.0)),
+    ("room temperature", Temperature::Celsius(20.0)),
+    ("body temperature", Temperature::Celsius(37.0)),
+    ("boiling point of water", Temperature::Celsius(100.0)),
+];
+
+impl Temperature {
+    /// The Planck temperature, about 1.417 × 10³² K.
+    ///
+    /// That's the hottest temperature that current physics can say anything
+    /// about. It fits in an `f32` just fine (the max is around 3.4 × 10³⁸),
+    /// but be careful with math: converting it to Fahrenheit, or squaring
+    /// it, can still overflow to infinity on `f32`.
+    pub const PLANCK_TEMPERATURE: Temperature = Temperature::Kelvin(1.416_784e32);
+
+    /// The temperature at the core of the Sun, about 15.7 million K.
+    pub const SUN_CORE: Temperature = Temperature::Kelvin(1.57e7);
+
+    /// The boiling point of liquid nitrogen at standard pressure, 77.35 K
+    /// (-195.8° C).
+    pub const LIQUID_NITROGEN_BOILING: Temperature = Temperature::Kelvin(77.35);
+
+    /// The temperature of the cosmic microwave background (CMB), the
+    /// leftover glow of the Big Bang, at 2.725 K.
+    pub const CMB: Temperature = Temperature::Kelvin(2.725);
+
+    /// Finds the [reference point](REFERENCE_POINTS) closest to `self`,
+    /// returning its name and value.
+    ///
+    /// Distances are compared in Kelvin, so any unit works. If `self` is
+    /// `NaN`, nothing is closer than anything else, and you'll get the first
+    /// reference point (absolute zero).
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// let (name, _) = Temperature::Fahrenheit(210.0).nearest_reference();
+    /// assert_eq!(name, "boiling point of water");
+    /// ```
+    pub fn nearest_reference(&self) -> (&'static str, Temperature) {
+        let k = self.to_kelvin().into_inner();
+        let distance = |t: &Temperature| (t.to_kelvin().into_inner() - k).abs();
+
+        let mut nearest = REFERENCE_POINTS[0];
+
+        for reference in &REFERENCE_POINTS[1..] {
+            if distance(&reference.1) < distance(&nearest.1) {
+                nearest = *reference;
+            }
+        }
+
+        nearest
+    }
+
+    /// Describes `self` relative to the nearer of water's freezing and
+    /// boiling points.
+    ///
+    /// You get back the signed difference in degrees Celsius (positive means
+    /// warmer) along with a label, so you can write things like
+    /// "12°C below boiling". The labels are:
+    ///
+    /// - `"below freezing"`, `"at freezing"`, and `"above freezing"`
+    /// - `"below boiling"`, `"at boiling"`, and `"above boiling"`
+    /// - `"unknown"`, for `NaN` (which comes with a `NaN` difference)
+    ///
+    /// At exactly 50°C, both points are just as close. That tie goes to
+    /// freezing, so you'll get `(50.0, "above freezing")`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let (delta, label) = Temperature::Celsius(88.0).relation_to_water();
+    ///
+    /// assert_approx_eq!(delta, -12.0);
+    /// assert_eq!(label, "below boiling");
+    /// ```
+    pub fn relation_to_water(&self) -> (Float, &'static str) {
+        let c = self.to_celsius().into_inner();
+
+        if c.is_nan() {
+            return (c, "unknown");
+        }
+
+        if c <= 50.0 {
+            let label = match c {
+                c if c < 0.0 => "below freezing",
+                c if c > 0.0 => "above freezing",
+                _ => "at freezing",
+            };
+
+            (c, label)
+        } else {
+            let delta = c - 100.0;
+            let label = match delta {
+                d if d < 0.0 => "below boiling",
+                d if d > 0.0 => "above boiling",
+                _ => "at boiling",
+            };
+
+            (delta, label)
+        }
+    }
+
+    /// Checks if `self` is within `epsilon` degrees of -40, the one spot
+    /// where Celsius and Fahrenheit read the same.
+    ///
+    /// `self` is converted to Celsius first, and `epsilon` is in degrees
+    /// Celsius. The check is inclusive. `NaN` is never a crossover.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::Temperature;
+    /// #
+    /// assert!(Temperature::Fahrenheit(-40.0).is_scale_crossover(1e-9));
+    /// assert!(!Temperature::Celsius(-39.0).is_scale_crossover(0.5));
+    /// ```
+    pub fn is_scale_crossover(&self, epsilon: Float) -> bool {
+        (self.to_celsius().into_inner() + 40.0).abs() <= epsilon
+    }
+}
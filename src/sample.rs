@@ -0,0 +1,102 @@
+//! # Sample
+//!
+//! A tiny timestamped reading, for logging without pulling in a time crate.
+//!
+//! Timestamps are plain milliseconds from whatever clock you've got (like a
+//! millisecond tick counter on your microcontroller).
+
+use core::cmp::Ordering;
+
+use crate::Temperature;
+
+/// A [Temperature] reading along with when it was taken, in milliseconds.
+///
+/// Samples are ordered by timestamp, so a sorted buffer of them is in time
+/// order. Two samples taken at the same time are ordered physically (see
+/// [Temperature::total_cmp_kelvin]), and they're only equal if they're the
+/// same temperature, too.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{sample::Sample, Temperature};
+/// #
+/// let mut log = [
+///     Sample::new(Temperature::Celsius(21.0), 2_000),
+///     Sample::new(Temperature::Celsius(20.5), 1_000),
+/// ];
+/// log.sort();
+///
+/// assert_eq!(log[0].millis(), 1_000);
+/// assert_eq!(log[0].age_from(5_000), 4_000);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Sample {
+    temp: Temperature,
+    millis: u64,
+}
+
+impl Sample {
+    /// Creates a new [Sample] taken at `millis`.
+    pub const fn new(temp: Temperature, millis: u64) -> Sample {
+        Sample { temp, millis }
+    }
+
+    /// The temperature that was read.
+    pub const fn temp(&self) -> Temperature {
+        self.temp
+    }
+
+    /// When the reading was taken, in milliseconds.
+    pub const fn millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// Returns how old this sample is at `now_millis`, in milliseconds.
+    ///
+    /// A sample from the "future" (taken after `now_millis`) is `0` ms old.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{sample::Sample, Temperature};
+    /// #
+    /// let sample = Sample::new(Temperature::Kelvin(300.0), 1_500);
+    ///
+    /// assert_eq!(sample.age_from(2_000), 500);
+    /// assert_eq!(sample.age_from(1_000), 0);
+    /// ```
+    pub const fn age_from(&self, now_millis: u64) -> u64 {
+        now_millis.saturating_sub(self.millis)
+    }
+}
+
+impl From<Sample> for Temperature {
+    fn from(value: Sample) -> Self {
+        value.temp
+    }
+}
+
+impl PartialEq for Sample {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Sample {}
+
+impl PartialOrd for Sample {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Sample {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.millis
+            .cmp(&other.millis)
+            .then_with(|| self.temp.total_cmp_kelvin(&other.temp))
+    }
+}
@@ -0,0 +1,117 @@
+//! # Scale
+//!
+//! Custom linear temperature scales.
+//!
+//! Simmer only has variants for Fahrenheit, Celsius, and Kelvin. But plenty of
+//! other scales exist, and some sensors report in their own strange units.
+//! If your scale is linear, you can describe it with a [LinearScale] and
+//! convert to and from it without needing a new [Temperature] variant.
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::{scale::LinearScale, Temperature};
+//! # use assert_approx_eq::assert_approx_eq;
+//!
+//! // the Rømer scale: water freezes at 7.5 and boils at 60
+//! let romer = LinearScale::new(100.0 / 52.5, -7.5 * 100.0 / 52.5);
+//!
+//! let boiling = romer.to_celsius(60.0);
+//! assert_approx_eq!(boiling.into_inner(), 100.0);
+//! ```
+
+use crate::{Float, Temperature};
+
+/// A user-defined linear temperature scale, described relative to Celsius.
+///
+/// The math is `celsius = value * slope + intercept`, so converting back is
+/// `value = (celsius - intercept) / slope`.
+///
+/// `abs_zero` is absolute zero, written in this scale. [LinearScale::new]
+/// works it out for you.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearScale {
+    pub slope: Float,
+    pub intercept: Float,
+    pub abs_zero: Float,
+}
+
+impl LinearScale {
+    /// Creates a new [LinearScale] from its `slope` and `intercept`,
+    /// calculating where absolute zero lands.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::scale::LinearScale;
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// // the Réaumur scale: 80 degrees between freezing and boiling
+    /// let reaumur = LinearScale::new(1.25, 0.0);
+    /// assert_approx_eq!(reaumur.abs_zero, -218.52);
+    /// ```
+    pub const fn new(slope: Float, intercept: Float) -> LinearScale {
+        LinearScale {
+            slope,
+            intercept,
+            abs_zero: (-273.15 - intercept) / slope,
+        }
+    }
+
+    /// Converts a `value` in this scale into a [Temperature] in Celsius.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{scale::LinearScale, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let reaumur = LinearScale::new(1.25, 0.0);
+    /// assert_approx_eq!(reaumur.to_celsius(80.0).into_inner(), 100.0);
+    /// ```
+    pub fn to_celsius(&self, value: Float) -> Temperature {
+        Temperature::Celsius(value * self.slope + self.intercept)
+    }
+
+    /// Converts a [Temperature] (in any unit) into a value in this scale.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{scale::LinearScale, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let reaumur = LinearScale::new(1.25, 0.0);
+    /// assert_approx_eq!(reaumur.from_celsius(Temperature::Fahrenheit(212.0)), 80.0);
+    /// ```
+    pub fn from_celsius(&self, c: Temperature) -> Float {
+        (c.to_celsius().into_inner() - self.intercept) / self.slope
+    }
+
+    /// Tells you if a `value` in this scale is colder than absolute zero.
+    ///
+    /// This works for "backwards" scales with a negative slope, too.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::scale::LinearScale;
+    /// #
+    /// let reaumur = LinearScale::new(1.25, 0.0);
+    ///
+    /// assert!(reaumur.is_below_abs_zero(-300.0));
+    /// assert!(!reaumur.is_below_abs_zero(20.0));
+    /// ```
+    pub fn is_below_abs_zero(&self, value: Float) -> bool {
+        if self.slope < 0.0 {
+            value > self.abs_zero
+        } else {
+            value < self.abs_zero
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! # Sensor
+//!
+//! A tiny, common interface for anything that reads a [Temperature].
+//!
+//! Sensor driver crates can implement [TemperatureSensor] so their users get
+//! a plain `simmer::Temperature` back, no matter which chip is on the board.
+//! It has no dependencies and works without `std`.
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::{sensor::TemperatureSensor, Temperature};
+//!
+//! /// A pretend driver that reads the chip's raw 1/16 °C counts.
+//! struct Ds18b20 {
+//!     raw: i16,
+//! }
+//!
+//! impl TemperatureSensor for Ds18b20 {
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn read_temperature(&mut self) -> Result<Temperature, Self::Error> {
+//!         Ok(Temperature::Celsius(self.raw as f64 / 16.0))
+//!     }
+//! }
+//!
+//! let mut sensor = Ds18b20 { raw: 0x0191 };
+//! assert_eq!(sensor.read_temperature(), Ok(Temperature::Celsius(25.0625)));
+//! ```
+
+use crate::Temperature;
+
+/// Something that can read a [Temperature].
+///
+/// `Error` is whatever can go wrong while reading, like a bus error or a bad
+/// checksum. Sensors that can't fail can use `core::convert::Infallible`.
+pub trait TemperatureSensor {
+    /// The error returned when a reading fails.
+    type Error;
+
+    /// Takes a reading.
+    fn read_temperature(&mut self) -> Result<Temperature, Self::Error>;
+}
+
+impl<T: TemperatureSensor + ?Sized> TemperatureSensor for &mut T {
+    type Error = T::Error;
+
+    fn read_temperature(&mut self) -> Result<Temperature, Self::Error> {
+        T::read_temperature(self)
+    }
+}
+
+/// A mock sensor that always reads the same [Temperature].
+///
+/// It's meant for testing code that takes a [TemperatureSensor], without
+/// any hardware around.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{sensor::{FixedSensor, TemperatureSensor}, Temperature};
+/// #
+/// let mut sensor = FixedSensor::new(Temperature::Celsius(21.5));
+///
+/// assert_eq!(sensor.read_temperature(), Ok(Temperature::Celsius(21.5)));
+/// assert_eq!(sensor.reads(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedSensor {
+    temp: Temperature,
+    reads: u32,
+}
+
+impl FixedSensor {
+    /// Creates a mock sensor that always reads `temp`.
+    pub const fn new(temp: Temperature) -> FixedSensor {
+        FixedSensor { temp, reads: 0 }
+    }
+
+    /// Changes the temperature that future readings return.
+    pub fn set(&mut self, temp: Temperature) {
+        self.temp = temp;
+    }
+
+    /// Returns how many readings have been taken so far. It saturates at
+    /// `u32::MAX`.
+    pub const fn reads(&self) -> u32 {
+        self.reads
+    }
+}
+
+impl TemperatureSensor for FixedSensor {
+    type Error = core::convert::Infallible;
+
+    fn read_temperature(&mut self) -> Result<Temperature, Self::Error> {
+        self.reads = self.reads.saturating_add(1);
+        Ok(self.temp)
+    }
+}
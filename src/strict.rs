@@ -0,0 +1,141 @@
+//! # Strict
+//!
+//! A [Temperature] wrapper that refuses to mix units in arithmetic.
+//!
+//! Adding a [Temperature] to another converts the right side into the left
+//! side's unit, silently. That's usually what you want! But when you're
+//! chasing down a bug, it's nice to know whether two values were in
+//! different units in the first place. [StrictTemperature] is a debugging aid
+//! for exactly that.
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::{strict::StrictTemperature, Temperature};
+//!
+//! let a = StrictTemperature::new(Temperature::Celsius(20.0));
+//! let b = StrictTemperature::new(Temperature::Celsius(5.0));
+//!
+//! assert_eq!((a + b).get(), Temperature::Celsius(25.0));
+//!
+//! // mixing units is an error
+//! let f = StrictTemperature::new(Temperature::Fahrenheit(5.0));
+//! assert!(a.try_add(f).is_err());
+//! ```
+
+use onlyerror::Error;
+
+use crate::{Temperature, Unit};
+
+/// An error from arithmetic on a [StrictTemperature].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum StrictTemperatureError {
+    #[error("Can't combine a {left} temperature with a {right} one.")]
+    UnitMismatch { left: Unit, right: Unit },
+}
+
+/// A [Temperature] whose `+` and `-` only work when both sides have the same
+/// unit.
+///
+/// Mixing units with `+` or `-` **panics**, so you'll find out right away
+/// during development. If you'd rather handle it, use
+/// [StrictTemperature::try_add] and [StrictTemperature::try_sub].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct StrictTemperature(Temperature);
+
+impl StrictTemperature {
+    /// Wraps a [Temperature].
+    pub const fn new(temp: Temperature) -> StrictTemperature {
+        StrictTemperature(temp)
+    }
+
+    /// Returns the wrapped [Temperature].
+    pub const fn get(&self) -> Temperature {
+        self.0
+    }
+
+    /// Makes sure both sides share a unit.
+    fn same_unit(&self, rhs: &StrictTemperature) -> Result<(), StrictTemperatureError> {
+        if self.0.unit() != rhs.0.unit() {
+            return Err(StrictTemperatureError::UnitMismatch {
+                left: self.0.unit(),
+                right: rhs.0.unit(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Adds two temperatures, failing if their units differ.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{strict::StrictTemperature, Temperature};
+    /// #
+    /// let a = StrictTemperature::new(Temperature::Kelvin(300.0));
+    /// let b = StrictTemperature::new(Temperature::Kelvin(1.0));
+    ///
+    /// assert_eq!(a.try_add(b).unwrap().get(), Temperature::Kelvin(301.0));
+    /// ```
+    pub fn try_add(
+        self,
+        rhs: StrictTemperature,
+    ) -> Result<StrictTemperature, StrictTemperatureError> {
+        self.same_unit(&rhs)?;
+        Ok(StrictTemperature(self.0 + rhs.0))
+    }
+
+    /// Subtracts two temperatures, failing if their units differ.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{strict::StrictTemperature, Temperature};
+    /// #
+    /// let a = StrictTemperature::new(Temperature::Kelvin(300.0));
+    /// let b = StrictTemperature::new(Temperature::Celsius(1.0));
+    ///
+    /// assert!(a.try_sub(b).is_err());
+    /// ```
+    pub fn try_sub(
+        self,
+        rhs: StrictTemperature,
+    ) -> Result<StrictTemperature, StrictTemperatureError> {
+        self.same_unit(&rhs)?;
+        Ok(StrictTemperature(self.0 - rhs.0))
+    }
+}
+
+impl From<Temperature> for StrictTemperature {
+    fn from(value: Temperature) -> Self {
+        StrictTemperature::new(value)
+    }
+}
+
+impl core::ops::Add for StrictTemperature {
+    type Output = Self;
+
+    /// Panics if the units differ!
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.try_add(rhs) {
+            Ok(sum) => sum,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl core::ops::Sub for StrictTemperature {
+    type Output = Self;
+
+    /// Panics if the units differ!
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.try_sub(rhs) {
+            Ok(difference) => difference,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! # Substance
+//!
+//! Checks what state (solid, liquid, or gas) a few common substances are in
+//! at a given [Temperature].
+//!
+//! All of the melting and boiling points here assume standard atmospheric
+//! pressure (1 atm, or 101.325 kPa). They'll be wrong at altitude, in a
+//! pressure cooker, and so on!
+
+use crate::{Float, Temperature};
+
+/// A substance with known melting and boiling points at standard pressure.
+///
+/// Carbon dioxide is a bit special: at standard pressure, it never melts. It
+/// *sublimates*, going straight from solid (dry ice) to gas at -78.46° C. So,
+/// it's never a liquid here.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Substance {
+    Water,
+    Nitrogen,
+    Mercury,
+    Ethanol,
+    CO2,
+}
+
+impl Substance {
+    /// The `(melting, boiling)` points in Celsius.
+    ///
+    /// For CO2, both are its sublimation point.
+    const fn points_c(&self) -> (Float, Float) {
+        match self {
+            Substance::Water => (0.0, 100.0),
+            Substance::Nitrogen => (-210.0, -195.8),
+            Substance::Mercury => (-38.83, 356.73),
+            Substance::Ethanol => (-114.1, 78.37),
+            Substance::CO2 => (-78.46, -78.46),
+        }
+    }
+
+    /// The temperature where this substance stops being a solid.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{substance::Substance, Temperature};
+    /// #
+    /// assert_eq!(Substance::Water.melting_point(), Temperature::Celsius(0.0));
+    /// ```
+    pub const fn melting_point(&self) -> Temperature {
+        Temperature::Celsius(self.points_c().0)
+    }
+
+    /// The temperature where this substance becomes a gas.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{substance::Substance, Temperature};
+    /// #
+    /// assert_eq!(Substance::Water.boiling_point(), Temperature::Celsius(100.0));
+    /// ```
+    pub const fn boiling_point(&self) -> Temperature {
+        Temperature::Celsius(self.points_c().1)
+    }
+}
+
+impl Temperature {
+    /// Checks if `substance` is a solid at this temperature (at standard
+    /// pressure).
+    ///
+    /// That's anything strictly below its melting point. `NaN` is never
+    /// a solid.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{substance::Substance, Temperature};
+    /// #
+    /// let freezer = Temperature::Fahrenheit(0.0);
+    ///
+    /// assert!(freezer.is_solid(Substance::Water));
+    /// assert!(!freezer.is_solid(Substance::Mercury));
+    /// ```
+    pub fn is_solid(&self, substance: Substance) -> bool {
+        self.to_celsius().into_inner() < substance.points_c().0
+    }
+
+    /// Checks if `substance` is a liquid at this temperature (at standard
+    /// pressure).
+    ///
+    /// That's anything from its melting point up to (but not including) its
+    /// boiling point. CO2 is never a liquid, and neither is `NaN`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{substance::Substance, Temperature};
+    /// #
+    /// let room = Temperature::Celsius(20.0);
+    ///
+    /// assert!(room.is_liquid(Substance::Ethanol));
+    /// assert!(!room.is_liquid(Substance::CO2));
+    /// ```
+    pub fn is_liquid(&self, substance: Substance) -> bool {
+        let c = self.to_celsius().into_inner();
+        let (melting, boiling) = substance.points_c();
+
+        c >= melting && c < boiling
+    }
+
+    /// Checks if `substance` is a gas at this temperature (at standard
+    /// pressure).
+    ///
+    /// That's anything at or above its boiling point. `NaN` is never a gas.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{substance::Substance, Temperature};
+    /// #
+    /// let kettle = Temperature::Celsius(100.0);
+    /// assert!(kettle.is_gas(Substance::Water));
+    /// ```
+    pub fn is_gas(&self, substance: Substance) -> bool {
+        self.to_celsius().into_inner() >= substance.points_c().1
+    }
+}
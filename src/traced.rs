@@ -0,0 +1,106 @@
+//! # Traced
+//!
+//! A [Temperature] that remembers where it came from.
+//!
+//! Chaining conversions (Fahrenheit to Celsius to Kelvin to...) rounds a
+//! little at every step. [TracedTemperature] keeps the original reading
+//! around and re-derives every conversion from it, so nothing piles up. It
+//! also gives you an audit trail: you can always see the exact value and
+//! unit that was first read.
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::{traced::TracedTemperature, Temperature};
+//!
+//! let reading = TracedTemperature::new(Temperature::Fahrenheit(98.6));
+//! let converted = reading.to_celsius().to_kelvin().to_fahrenheit();
+//!
+//! // still the exact value that came in
+//! assert_eq!(converted.current(), Temperature::Fahrenheit(98.6));
+//! assert_eq!(converted.original(), Temperature::Fahrenheit(98.6));
+//! ```
+
+use crate::{Temperature, Unit};
+
+/// A [Temperature] that keeps its original reading alongside its current
+/// value.
+///
+/// Every conversion converts the *original*, never the current value, so
+/// converting over and over can't lose any precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TracedTemperature {
+    original: Temperature,
+    current: Temperature,
+}
+
+impl TracedTemperature {
+    /// Starts tracing a [Temperature]. It's both the original and the
+    /// current value.
+    pub const fn new(temp: Temperature) -> TracedTemperature {
+        TracedTemperature {
+            original: temp,
+            current: temp,
+        }
+    }
+
+    /// Returns the original reading, exactly as it came in.
+    pub const fn original(&self) -> Temperature {
+        self.original
+    }
+
+    /// Returns the current value, in whatever unit it was last converted to.
+    pub const fn current(&self) -> Temperature {
+        self.current
+    }
+
+    /// Converts into the given [Unit], starting from the original reading.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{traced::TracedTemperature, Temperature, Unit};
+    /// #
+    /// let reading = TracedTemperature::new(Temperature::Celsius(21.5));
+    /// let kelvin = reading.to_unit(Unit::Kelvin);
+    ///
+    /// assert_eq!(kelvin.current(), Temperature::Celsius(21.5).to_kelvin());
+    /// assert_eq!(kelvin.original(), Temperature::Celsius(21.5));
+    /// ```
+    pub fn to_unit(&self, unit: Unit) -> TracedTemperature {
+        TracedTemperature {
+            original: self.original,
+            current: self.original.to_unit(unit),
+        }
+    }
+
+    /// Converts into Fahrenheit, starting from the original reading.
+    pub fn to_fahrenheit(&self) -> TracedTemperature {
+        self.to_unit(Unit::Fahrenheit)
+    }
+
+    /// Converts into Celsius, starting from the original reading.
+    pub fn to_celsius(&self) -> TracedTemperature {
+        self.to_unit(Unit::Celsius)
+    }
+
+    /// Converts into Kelvin, starting from the original reading.
+    pub fn to_kelvin(&self) -> TracedTemperature {
+        self.to_unit(Unit::Kelvin)
+    }
+}
+
+impl From<Temperature> for TracedTemperature {
+    fn from(value: Temperature) -> Self {
+        TracedTemperature::new(value)
+    }
+}
+
+impl From<TracedTemperature> for Temperature {
+    /// Takes the current value.
+    fn from(value: TracedTemperature) -> Self {
+        value.current
+    }
+}
@@ -0,0 +1,171 @@
+//! # Tracking
+//!
+//! Stateful helpers for following a stream of [Temperature] readings over
+//! time.
+//!
+//! These are small, `Copy`-able structs that you feed one reading at a time.
+//! They don't allocate, so they're happy to live in a `static` on your
+//! microcontroller.
+
+use crate::{Float, Temperature, Unit};
+
+/// Tracks how quickly a temperature is changing, in Kelvin per second.
+///
+/// Feed it readings with [RateTracker::update]. It remembers the previous
+/// reading (normalized to Kelvin) and its timestamp, then reports the rate of
+/// change since then. That's the derivative term you'd want in a PID loop!
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{tracking::RateTracker, Temperature};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let mut tracker = RateTracker::new();
+///
+/// // the first reading has nothing to compare against
+/// assert_eq!(tracker.update(Temperature::Celsius(20.0), 0.0), None);
+///
+/// // two seconds later, it's a degree warmer
+/// let rate = tracker.update(Temperature::Celsius(21.0), 2.0).unwrap();
+/// assert_approx_eq!(rate, 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateTracker {
+    /// The previous reading in Kelvin, along with its timestamp in seconds.
+    last: Option<(Float, Float)>,
+}
+
+impl RateTracker {
+    /// Creates a new [RateTracker] that hasn't seen any readings yet.
+    pub const fn new() -> RateTracker {
+        RateTracker { last: None }
+    }
+
+    /// Records a new reading taken at `timestamp_secs`, returning the rate of
+    /// change since the previous reading in Kelvin per second.
+    ///
+    /// Returns `None` when there's no meaningful rate:
+    ///
+    /// - on the very first reading, and
+    /// - when the time delta is zero or negative (like a clock that got
+    ///   reset).
+    ///
+    /// In the second case, the new reading still replaces the old one, so the
+    /// tracker picks right back up on the next update.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{tracking::RateTracker, Temperature};
+    /// #
+    /// let mut tracker = RateTracker::new();
+    /// tracker.update(Temperature::Kelvin(300.0), 10.0);
+    ///
+    /// // time went backwards, so there's no rate to report
+    /// assert_eq!(tracker.update(Temperature::Kelvin(301.0), 5.0), None);
+    /// ```
+    pub fn update(&mut self, t: Temperature, timestamp_secs: Float) -> Option<Float> {
+        let kelvin = t.to_kelvin().into_inner();
+        let previous = self.last.replace((kelvin, timestamp_secs));
+
+        let (last_kelvin, last_timestamp) = previous?;
+        let dt = timestamp_secs - last_timestamp;
+
+        if dt <= 0.0 {
+            return None;
+        }
+
+        Some((kelvin - last_kelvin) / dt)
+    }
+
+    /// Forgets the previous reading, as if the tracker was just created.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+/// Remembers the recent high, slowly forgetting old ones.
+///
+/// A plain running max holds onto a spike forever. [DecayingPeak] lets the
+/// stored peak sag back toward the current reading with every update, so it
+/// tracks *recent* highs using a single float instead of a buffer.
+///
+/// Everything is stored in Kelvin, and [DecayingPeak::peak] reports it in
+/// the unit of the latest reading.
+///
+/// # Usage
+///
+#[cfg_attr(feature = "f32", doc = "```ignore")]
+#[cfg_attr(not(feature = "f32"), doc = "```")]
+/// # use simmer::{tracking::DecayingPeak, Temperature};
+/// # use assert_approx_eq::assert_approx_eq;
+/// #
+/// let mut peak = DecayingPeak::new(Temperature::Celsius(20.0));
+///
+/// // a spike sets the peak right away...
+/// peak.update(Temperature::Celsius(30.0), 0.5);
+/// assert_approx_eq!(peak.peak().into_inner(), 30.0);
+///
+/// // ...then it sags halfway back toward each new reading
+/// peak.update(Temperature::Celsius(20.0), 0.5);
+/// assert_approx_eq!(peak.peak().into_inner(), 25.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecayingPeak {
+    /// The stored peak, in Kelvin.
+    peak: Float,
+    /// The unit of the latest reading.
+    unit: Unit,
+}
+
+impl DecayingPeak {
+    /// Starts tracking with `initial` as the peak.
+    pub fn new(initial: Temperature) -> DecayingPeak {
+        DecayingPeak {
+            peak: initial.to_kelvin().into_inner(),
+            unit: initial.unit(),
+        }
+    }
+
+    /// Feeds in a new reading.
+    ///
+    /// If `t` is at or above the stored peak, it becomes the new peak.
+    /// Otherwise, the peak relaxes toward `t` by the fraction `decay`:
+    ///
+    /// `peak = peak - decay * (peak - t)`
+    ///
+    /// So `decay` of `0.0` holds the peak forever (a plain running max), and
+    /// `1.0` just follows the readings. It's clamped to `0.0..=1.0`, and a
+    /// `NaN` decay is treated as `0.0`.
+    ///
+    /// `NaN` readings are ignored entirely, leaving both the peak and its
+    /// unit alone.
+    pub fn update(&mut self, t: Temperature, decay: Float) {
+        if t.is_nan() {
+            return;
+        }
+
+        let kelvin = t.to_kelvin().into_inner();
+        self.unit = t.unit();
+
+        let decay = if decay.is_nan() {
+            0.0
+        } else {
+            decay.clamp(0.0, 1.0)
+        };
+
+        if kelvin >= self.peak || self.peak.is_nan() {
+            self.peak = kelvin;
+        } else {
+            self.peak -= decay * (self.peak - kelvin);
+        }
+    }
+
+    /// Returns the current peak, in the unit of the latest reading.
+    pub fn peak(&self) -> Temperature {
+        Temperature::Kelvin(self.peak).to_unit(self.unit)
+    }
+}
@@ -0,0 +1,198 @@
+//! # Typed
+//!
+//! Temperatures with their unit baked into the type.
+//!
+//! A [Temperature] carries its unit around at runtime, and mixing units just
+//! converts behind your back. [TypedTemp] moves the unit into the type
+//! system instead: a `TypedTemp<Celsius>` and a `TypedTemp<Fahrenheit>` are
+//! different types, so the compiler won't let you add them together until
+//! you [convert](TypedTemp::convert) one. It's just a float underneath, and
+//! conversions are constant coefficients, with no enum to match on.
+//!
+//! This is opt-in. Use [TypedTemp::to_temperature] (or `From`) to go back to
+//! a regular [Temperature] whenever you need to.
+//!
+//! ## Usage
+//!
+#![cfg_attr(feature = "f32", doc = "```ignore")]
+#![cfg_attr(not(feature = "f32"), doc = "```")]
+//! use simmer::typed::{Celsius, Fahrenheit, TypedTemp};
+//! # use assert_approx_eq::assert_approx_eq;
+//!
+//! let room = TypedTemp::<Celsius>::new(20.0);
+//! let outside = TypedTemp::<Fahrenheit>::new(41.0);
+//!
+//! // `room + outside` won't compile, so convert first
+//! let total = room + outside.convert::<Celsius>();
+//! assert_approx_eq!(total.value(), 25.0);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{
+    convert::{apply_coeffs, linear_coeffs},
+    Float, Temperature, Unit,
+};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Fahrenheit {}
+    impl Sealed for super::Celsius {}
+    impl Sealed for super::Kelvin {}
+}
+
+/// A type that stands in for a temperature [Unit].
+///
+/// This is sealed: [Fahrenheit], [Celsius], and [Kelvin] are the only
+/// implementors.
+pub trait UnitMarker:
+    sealed::Sealed + Clone + Copy + core::fmt::Debug + Default + PartialEq + PartialOrd
+{
+    /// The runtime [Unit] this marker stands for.
+    const UNIT: Unit;
+}
+
+/// Marks a [TypedTemp] as degrees Fahrenheit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fahrenheit;
+
+/// Marks a [TypedTemp] as degrees Celsius.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Celsius;
+
+/// Marks a [TypedTemp] as Kelvin.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Kelvin;
+
+impl UnitMarker for Fahrenheit {
+    const UNIT: Unit = Unit::Fahrenheit;
+}
+
+impl UnitMarker for Celsius {
+    const UNIT: Unit = Unit::Celsius;
+}
+
+impl UnitMarker for Kelvin {
+    const UNIT: Unit = Unit::Kelvin;
+}
+
+/// A temperature whose unit is part of its type.
+///
+/// Like [Temperature], this is *not* checked, so invalid states are allowed.
+///
+/// Mixing units doesn't compile:
+///
+/// ```compile_fail
+/// # use simmer::typed::{Celsius, Fahrenheit, TypedTemp};
+/// #
+/// let c = TypedTemp::<Celsius>::new(20.0);
+/// let f = TypedTemp::<Fahrenheit>::new(68.0);
+///
+/// let oops = c + f;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct TypedTemp<U: UnitMarker>(Float, PhantomData<U>);
+
+impl<U: UnitMarker> TypedTemp<U> {
+    /// Creates a typed temperature from a value in unit `U`.
+    pub const fn new(value: Float) -> TypedTemp<U> {
+        TypedTemp(value, PhantomData)
+    }
+
+    /// Returns the value, in unit `U`.
+    pub const fn value(&self) -> Float {
+        self.0
+    }
+
+    /// Returns the runtime [Unit] that `U` stands for.
+    pub const fn unit(&self) -> Unit {
+        U::UNIT
+    }
+
+    /// Converts into another unit, `V`.
+    ///
+    /// Both units are known at compile time, so this never matches on a
+    /// [Unit]. The `(scale, offset)` pair from [linear_coeffs] is baked in as
+    /// a constant, and converting is a single multiply-add. The result can
+    /// differ from converting the equivalent [Temperature] by a rounding
+    /// error, since the math is arranged a little differently.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::typed::{Celsius, Kelvin, TypedTemp};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let ice = TypedTemp::<Celsius>::new(0.0);
+    /// let ice_k: TypedTemp<Kelvin> = ice.convert();
+    ///
+    /// assert_approx_eq!(ice_k.value(), 273.15);
+    /// ```
+    pub fn convert<V: UnitMarker>(self) -> TypedTemp<V> {
+        let coeffs = const { linear_coeffs(U::UNIT, V::UNIT) };
+
+        TypedTemp::new(apply_coeffs(self.0, coeffs))
+    }
+
+    /// Turns this back into a regular [Temperature], without converting.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{typed::{Fahrenheit, TypedTemp}, Temperature};
+    /// #
+    /// let body = TypedTemp::<Fahrenheit>::new(98.6);
+    /// assert_eq!(body.to_temperature(), Temperature::Fahrenheit(98.6));
+    /// ```
+    pub fn to_temperature(&self) -> Temperature {
+        Temperature::from((self.0, U::UNIT))
+    }
+
+    /// Converts any [Temperature] into unit `U`.
+    ///
+    /// # Usage
+    ///
+    #[cfg_attr(feature = "f32", doc = "```ignore")]
+    #[cfg_attr(not(feature = "f32"), doc = "```")]
+    /// # use simmer::{typed::{Celsius, TypedTemp}, Temperature};
+    /// # use assert_approx_eq::assert_approx_eq;
+    /// #
+    /// let boiling = TypedTemp::<Celsius>::from_temperature(Temperature::Fahrenheit(212.0));
+    /// assert_approx_eq!(boiling.value(), 100.0);
+    /// ```
+    pub fn from_temperature(temp: Temperature) -> TypedTemp<U> {
+        TypedTemp::new(temp.to_unit(U::UNIT).into_inner())
+    }
+}
+
+impl<U: UnitMarker> From<Temperature> for TypedTemp<U> {
+    /// Converts the [Temperature] into unit `U`.
+    fn from(value: Temperature) -> Self {
+        TypedTemp::from_temperature(value)
+    }
+}
+
+impl<U: UnitMarker> From<TypedTemp<U>> for Temperature {
+    fn from(value: TypedTemp<U>) -> Self {
+        value.to_temperature()
+    }
+}
+
+impl<U: UnitMarker> core::ops::Add for TypedTemp<U> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        TypedTemp::new(self.0 + rhs.0)
+    }
+}
+
+impl<U: UnitMarker> core::ops::Sub for TypedTemp<U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        TypedTemp::new(self.0 - rhs.0)
+    }
+}
@@ -0,0 +1,133 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{calibration::LookupTable, Calibration, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn unity_calibration() {
+    let cal = Calibration::default();
+    assert_eq!(cal, Calibration::new(1.0, 0.0));
+
+    for temp in [
+        Temperature::Fahrenheit(98.6),
+        Temperature::Celsius(-40.0),
+        Temperature::Kelvin(0.0),
+    ] {
+        assert_eq!(temp.calibrate(&cal), temp);
+    }
+}
+
+#[test]
+fn gain_and_offset() {
+    let cal = Calibration {
+        gain: 1.5,
+        offset: -2.0,
+    };
+
+    // the unit is kept, and the offset is in that unit
+    let c = Temperature::Celsius(10.0).calibrate(&cal);
+    assert!(matches!(c, Temperature::Celsius(_)));
+    assert_approx_eq!(c.into_inner(), 13.0);
+
+    let f = Temperature::Fahrenheit(10.0).calibrate(&cal);
+    assert!(matches!(f, Temperature::Fahrenheit(_)));
+    assert_approx_eq!(f.into_inner(), 13.0);
+}
+
+#[test]
+fn lookup_table() {
+    let table = LookupTable::new([
+        (-1.0, Temperature::Celsius(-50.0)),
+        (0.0, Temperature::Celsius(0.0)),
+        (2.0, Temperature::Celsius(100.0)),
+    ]);
+
+    // right on the entries
+    assert_approx_eq!(table.interpolate(-1.0).into_inner(), -50.0);
+    assert_approx_eq!(table.interpolate(0.0).into_inner(), 0.0);
+    assert_approx_eq!(table.interpolate(2.0).into_inner(), 100.0);
+
+    // between them
+    assert_approx_eq!(table.interpolate(-0.5).into_inner(), -25.0);
+    assert_approx_eq!(table.interpolate(0.5).into_inner(), 25.0);
+
+    // out of range inputs are clamped
+    assert_eq!(table.interpolate(-10.0), Temperature::Celsius(-50.0));
+    assert_eq!(table.interpolate(10.0), Temperature::Celsius(100.0));
+
+    assert!(table.interpolate(Float::NAN).is_nan());
+}
+
+#[test]
+fn lookup_table_mixed_units() {
+    let table = LookupTable::new([
+        (0.0, Temperature::Celsius(0.0)),
+        (1.0, Temperature::Fahrenheit(212.0)),
+    ]);
+
+    // returned in the lower entry's unit
+    let halfway = table.interpolate(0.5);
+    assert!(matches!(halfway, Temperature::Celsius(_)));
+    assert_approx_eq!(halfway.into_inner(), 50.0);
+}
+
+#[test]
+fn lookup_table_tiny() {
+    let empty: LookupTable<0> = LookupTable::new([]);
+    assert!(empty.interpolate(1.0).is_nan());
+
+    let single = LookupTable::new([(1.0, Temperature::Kelvin(4.0))]);
+    assert_eq!(single.interpolate(0.0), Temperature::Kelvin(4.0));
+    assert_eq!(single.interpolate(2.0), Temperature::Kelvin(4.0));
+
+    // duplicate voltages don't divide by zero
+    let dup = LookupTable::new([
+        (0.0, Temperature::Kelvin(1.0)),
+        (1.0, Temperature::Kelvin(2.0)),
+        (1.0, Temperature::Kelvin(3.0)),
+        (2.0, Temperature::Kelvin(4.0)),
+    ]);
+    assert!(!dup.interpolate(1.0).is_nan());
+}
+
+#[test]
+fn from_percent() {
+    let lower = Temperature::Celsius(0.0);
+    let upper = Temperature::Celsius(50.0);
+
+    assert_eq!(Temperature::from_percent(0.0, lower, upper), lower);
+    assert_approx_eq!(
+        Temperature::from_percent(50.0, lower, upper).into_inner(),
+        25.0
+    );
+    assert_approx_eq!(
+        Temperature::from_percent(100.0, lower, upper).into_inner(),
+        50.0
+    );
+}
+
+#[test]
+fn from_percent_mixed_units() {
+    // 32 F to 212 F is 0 C to 100 C
+    let lower = Temperature::Celsius(0.0);
+    let upper = Temperature::Fahrenheit(212.0);
+
+    let half = Temperature::from_percent(50.0, lower, upper);
+    assert!(matches!(half, Temperature::Celsius(_)));
+    assert_approx_eq!(half.into_inner(), 50.0, 1e-4);
+}
+
+#[test]
+fn from_percent_is_clamped() {
+    let lower = Temperature::Kelvin(200.0);
+    let upper = Temperature::Kelvin(400.0);
+
+    assert_eq!(Temperature::from_percent(-10.0, lower, upper), lower);
+    assert_eq!(Temperature::from_percent(150.0, lower, upper), upper);
+    assert!(Temperature::from_percent(Float::NAN, lower, upper).is_nan());
+}
@@ -1,7 +1,7 @@
 #![cfg(feature = "checked")]
 #![cfg(std)]
 use assert_approx_eq::assert_approx_eq;
-use simmer::{CheckedTemperature, Temperature};
+use simmer::{checked::CheckedTempError, CheckedTemperature, Temperature, Unit};
 
 // just like in the lib itself...
 #[cfg(not(feature = "f32"))]
@@ -205,3 +205,556 @@ fn bounds() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn new_rejects_non_finite() {
+    // these used to hit checks against `Float::MAX`/`Float::MIN`, which could
+    // never fail for a finite value...
+    assert!(CheckedTemperature::new(Temperature::Kelvin(Float::MAX)).is_ok());
+    assert!(CheckedTemperature::new(Temperature::Celsius(Float::MAX)).is_ok());
+
+    // ...while infinities slipped right through!
+    assert!(CheckedTemperature::new(Temperature::Kelvin(Float::INFINITY)).is_err());
+    assert!(CheckedTemperature::new(Temperature::Celsius(Float::NEG_INFINITY)).is_err());
+    assert!(CheckedTemperature::new(Temperature::Fahrenheit(Float::NAN)).is_err());
+
+    // setting a temperature enforces the same thing
+    let mut temp = CheckedTemperature::new(Temperature::Kelvin(1.0)).unwrap();
+    assert!(temp
+        .set_temperature(Temperature::Kelvin(Float::INFINITY))
+        .is_err());
+    assert!(temp.mul(Float::INFINITY).is_err());
+}
+
+#[test]
+fn with_bounds() -> anyhow::Result<()> {
+    let temp = CheckedTemperature::with_bounds(Temperature::Celsius(20.0), 0.0, 30.0)?;
+
+    let (lower, upper) = temp.get_bounds();
+    assert_approx_eq!(lower.into_inner(), 0.0);
+    assert_approx_eq!(upper.into_inner(), 30.0);
+
+    // outside of the given bounds
+    assert!(CheckedTemperature::with_bounds(Temperature::Celsius(31.0), 0.0, 30.0).is_err());
+    assert!(CheckedTemperature::with_bounds(Temperature::Celsius(-1.0), 0.0, 30.0).is_err());
+
+    // the bounds themselves are bad
+    assert!(CheckedTemperature::with_bounds(Temperature::Celsius(20.0), 30.0, 0.0).is_err());
+
+    // and still no infinities
+    assert!(
+        CheckedTemperature::with_bounds(Temperature::Celsius(Float::INFINITY), 0.0, 30.0).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn conversion_errors_name_units() -> anyhow::Result<()> {
+    let err = CheckedTempError::ConversionOutOfBounds {
+        value: 1.0,
+        from: Unit::Kelvin,
+        to: Unit::Fahrenheit,
+    };
+
+    assert!(format!("{err}").contains("out of bounds converting Kelvin → Fahrenheit"));
+    Ok(())
+}
+
+#[test]
+fn huge_upper_bound_is_unbounded() -> anyhow::Result<()> {
+    // an upper bound this big means "no bound", both when setting a
+    // temperature...
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_upper_bound(Float::MAX * 0.5)?;
+    temp.set_temperature(Temperature::Celsius(Float::MAX * 0.75))?;
+
+    // ...and when converting
+    let temp_k = temp.to_kelvin()?;
+    assert!(temp_k.get_inner() > Float::MAX * 0.5);
+    assert_eq!(temp_k.get_bounds().1.into_inner(), Float::MAX * 0.5);
+
+    // anything smaller is still a real bound
+    let mut temp = CheckedTemperature::new(Temperature::Kelvin(300.0))?;
+    temp.set_upper_bound(Float::MAX * 0.25)?;
+    assert!(matches!(
+        temp.set_temperature(Temperature::Kelvin(Float::MAX * 0.3)),
+        Err(CheckedTempError::TempOutOfBounds(..))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn absolute_zero_converts_to_every_unit() -> anyhow::Result<()> {
+    // float drift can land a hair below absolute zero in the new unit
+    // (especially with `f32`), but that's still absolute zero
+    let zeros = [
+        Temperature::Fahrenheit(-459.67),
+        Temperature::Celsius(-273.15),
+        Temperature::Kelvin(0.0),
+    ];
+
+    for zero in zeros {
+        let temp = CheckedTemperature::new(zero)?;
+        let (mut temp_c, mut temp_k) = (temp, temp);
+
+        assert!(temp.to_fahrenheit().is_ok(), "{zero:?} → F");
+        assert!(temp_c.to_celsius().is_ok(), "{zero:?} → C");
+        assert!(temp_k.to_kelvin().is_ok(), "{zero:?} → K");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn huge_finite_bounds_convert() -> anyhow::Result<()> {
+    // this upper bound is fine in kelvin, but it can't fit in fahrenheit!
+    let mut temp = CheckedTemperature::new(Temperature::Kelvin(300.0))?;
+    temp.set_upper_bound(Float::MAX * 0.9)?;
+
+    // so, it's treated as unbounded and left alone
+    let temp_f = temp.to_fahrenheit()?;
+    let (_, upper) = temp_f.get_bounds();
+
+    assert!(upper.into_inner().is_finite());
+    assert_eq!(upper.into_inner(), Float::MAX * 0.9);
+    assert_approx_eq!(temp_f.get_inner(), 80.33, 1e-2);
+
+    // same for a huge upper bound bouncing between every unit
+    let mut temp = temp_f;
+    for _ in 0..10 {
+        temp = temp.to_celsius()?.to_kelvin()?.to_fahrenheit()?;
+        assert!(temp.bounds_valid());
+        assert_eq!(temp.get_bounds().1.into_inner(), Float::MAX * 0.9);
+    }
+
+    // reasonable bounds still convert like normal
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_bounds(0.0, 1e6)?;
+
+    let (lower, upper) = temp.to_kelvin()?.get_bounds();
+    assert_approx_eq!(lower.into_inner(), 273.15, 1e-3);
+    assert_approx_eq!(upper.into_inner(), 1e6 + 273.15, 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn scale_kelvin() -> anyhow::Result<()> {
+    let temp = CheckedTemperature::new(Temperature::Kelvin(150.0))?;
+    assert_approx_eq!(temp.scale_kelvin(2.0)?.get_inner(), 300.0);
+    assert_approx_eq!(temp.scale_kelvin(0.0)?.get_inner(), 0.0);
+
+    // scaling happens physically, and the unit is kept
+    let ice = CheckedTemperature::new(Temperature::Celsius(0.0))?;
+    let scaled = ice.scale_kelvin(0.5)?;
+    assert!(matches!(scaled.get_unchecked(), Temperature::Celsius(_)));
+    assert_approx_eq!(scaled.get_inner(), -136.575);
+
+    // negative factors are rejected
+    assert!(matches!(
+        temp.scale_kelvin(-1.0),
+        Err(CheckedTempError::NegativeScale(_))
+    ));
+
+    // and bounds still apply
+    let bounded = CheckedTemperature::with_bounds(Temperature::Kelvin(150.0), 100.0, 200.0)?;
+    assert!(bounded.scale_kelvin(2.0).is_err());
+    assert!(bounded.scale_kelvin(1.2).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn same_unit_conversion_is_noop() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::with_bounds(Temperature::Celsius(21.0), -10.0, 50.0)?;
+    let before = temp;
+
+    assert_eq!(temp.to_celsius()?, before);
+    assert_eq!(temp, before);
+    assert_eq!(temp.get_bounds(), before.get_bounds());
+
+    Ok(())
+}
+
+#[test]
+fn fluent_chain() -> anyhow::Result<()> {
+    let ice = CheckedTemperature::new(Temperature::Fahrenheit(32.0))?;
+
+    // convert, do some math, and convert again - all in one go
+    let result = ice
+        .to_fahrenheit()?
+        .try_add(Temperature::Fahrenheit(18.0))?
+        .try_mul(2.0)?
+        .to_celsius()?
+        .try_sub(Temperature::Celsius(10.0))?
+        .try_div(2.0)?
+        .to_kelvin()?;
+
+    // (32 + 18) * 2 = 100 F -> 37.78 C -> 27.78 C -> 13.89 C
+    assert_approx_eq!(result.get_inner(), 287.039, 1e-3);
+
+    // the original is left alone
+    assert_approx_eq!(ice.get_inner(), 32.0);
+
+    Ok(())
+}
+
+#[test]
+fn fluent_chain_stops_on_error() -> anyhow::Result<()> {
+    let ice = CheckedTemperature::new(Temperature::Celsius(0.0))?;
+
+    let result = ice
+        .to_fahrenheit()
+        .and_then(|t| t.try_div(0.0))
+        .and_then(|t| t.try_add(Temperature::Celsius(1.0)));
+    assert!(matches!(result, Err(CheckedTempError::DivisionByZero)));
+
+    // going below absolute zero partway through fails, too
+    let result = ice
+        .try_sub(Temperature::Celsius(300.0))
+        .and_then(|t| t.try_add(Temperature::Celsius(300.0)));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn lower_bound_below_abs_zero() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+
+    assert!(matches!(
+        temp.set_lower_bound(-300.0),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+    assert!(matches!(
+        temp.set_bounds(-300.0, 50.0),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+    assert!(CheckedTemperature::with_bounds(Temperature::Celsius(20.0), -300.0, 50.0).is_err());
+
+    // the rejected bounds weren't applied
+    assert_eq!(
+        temp.get_bounds().0,
+        Temperature::Celsius(Float::NEG_INFINITY)
+    );
+
+    // absolute zero itself is fine, in any unit
+    temp.set_lower_bound(-273.15)?;
+
+    let mut temp_f = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    temp_f.set_lower_bound(-459.67)?;
+    assert!(temp_f.set_lower_bound(-460.0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_range() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_range(10.0..=30.0)?;
+
+    let (lower, upper) = temp.get_bounds();
+    assert_approx_eq!(lower.into_inner(), 10.0);
+    assert_approx_eq!(upper.into_inner(), 30.0);
+
+    assert!(temp.set_temperature(Temperature::Celsius(35.0)).is_err());
+    temp.set_temperature(Temperature::Celsius(25.0))?;
+
+    // a range completely above the old one works in one go
+    temp.set_range(40.0..=50.0)?;
+    assert_approx_eq!(temp.get_bounds().0.into_inner(), 40.0);
+
+    Ok(())
+}
+
+#[test]
+fn set_range_rejects_bad_ranges() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_range(10.0..=30.0)?;
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let inverted = temp.set_range(30.0..=10.0);
+    assert!(matches!(inverted, Err(CheckedTempError::BoundTooHigh(_))));
+
+    assert!(matches!(
+        temp.set_range(Float::NAN..=10.0),
+        Err(CheckedTempError::GivenValueIsNan)
+    ));
+    assert!(matches!(
+        temp.set_range(-300.0..=10.0),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+
+    // nothing changed
+    let (lower, upper) = temp.get_bounds();
+    assert_approx_eq!(lower.into_inner(), 10.0);
+    assert_approx_eq!(upper.into_inner(), 30.0);
+
+    Ok(())
+}
+
+#[test]
+fn set_range_temp() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Kelvin(300.0))?;
+    temp.set_range_temp(Temperature::Celsius(0.0)..=Temperature::Fahrenheit(212.0))?;
+
+    let (lower, upper) = temp.get_bounds();
+    assert_approx_eq!(lower.into_inner(), 273.15, 1e-3);
+    assert_approx_eq!(upper.into_inner(), 373.15, 1e-3);
+
+    assert!(matches!(
+        temp.set_range_temp(Temperature::Celsius(100.0)..=Temperature::Celsius(0.0)),
+        Err(CheckedTempError::BoundTooHigh(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn would_accept() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_bounds(0.0, 100.0)?;
+
+    // in bounds, including right on the edges
+    assert!(temp.would_accept(Temperature::Celsius(50.0)));
+    assert!(temp.would_accept(Temperature::Celsius(0.0)));
+    assert!(temp.would_accept(Temperature::Celsius(100.0)));
+
+    // out of bounds
+    assert!(!temp.would_accept(Temperature::Celsius(-1.0)));
+    assert!(!temp.would_accept(Temperature::Celsius(100.5)));
+
+    // never valid
+    assert!(!temp.would_accept(Temperature::Celsius(-300.0)));
+    assert!(!temp.would_accept(Temperature::Kelvin(-1.0)));
+    assert!(!temp.would_accept(Temperature::Celsius(Float::NAN)));
+    assert!(!temp.would_accept(Temperature::Celsius(Float::INFINITY)));
+
+    // it agrees with `set_temperature`, and doesn't change anything itself
+    assert_eq!(
+        temp.would_accept(Temperature::Celsius(150.0)),
+        temp.set_temperature(Temperature::Celsius(150.0)).is_ok()
+    );
+    assert_approx_eq!(temp.get_inner(), 20.0);
+
+    Ok(())
+}
+
+#[test]
+fn replace_temperature() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_bounds(0.0, 100.0)?;
+
+    // each replace hands back the one before it
+    assert_eq!(
+        temp.replace_temperature(Temperature::Celsius(30.0))?,
+        Temperature::Celsius(20.0)
+    );
+    assert_eq!(
+        temp.replace_temperature(Temperature::Fahrenheit(100.0))?,
+        Temperature::Celsius(30.0)
+    );
+    assert_eq!(temp.get_unchecked(), Temperature::Fahrenheit(100.0));
+
+    Ok(())
+}
+
+#[test]
+fn replace_temperature_failure_keeps_value() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_bounds(0.0, 100.0)?;
+
+    assert!(matches!(
+        temp.replace_temperature(Temperature::Celsius(150.0)),
+        Err(CheckedTempError::TempOutOfBounds(..))
+    ));
+    assert!(matches!(
+        temp.replace_temperature(Temperature::Kelvin(-1.0)),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+    assert!(matches!(
+        temp.replace_temperature(Temperature::Celsius(Float::NAN)),
+        Err(CheckedTempError::GivenValueIsNan)
+    ));
+
+    assert_eq!(temp.get_unchecked(), Temperature::Celsius(20.0));
+
+    Ok(())
+}
+
+#[test]
+fn bounds_can_be_cleared() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+
+    temp.set_lower_bound(10.0)?;
+    temp.set_upper_bound(30.0)?;
+    assert!(temp.set_temperature(Temperature::Celsius(5.0)).is_err());
+    assert!(temp.set_temperature(Temperature::Celsius(35.0)).is_err());
+
+    // back to unbounded, like a fresh `CheckedTemperature`
+    temp.set_lower_bound(Float::NEG_INFINITY)?;
+    temp.set_upper_bound(Float::INFINITY)?;
+    assert!(temp.bounds_valid());
+    temp.set_temperature(Temperature::Celsius(-200.0))?;
+    temp.set_temperature(Temperature::Celsius(5000.0))?;
+
+    // the other setters can clear them, too
+    temp.set_bounds(0.0, 6000.0)?;
+    temp.set_bounds(Float::NEG_INFINITY, Float::INFINITY)?;
+    temp.set_range(0.0..=6000.0)?;
+    temp.set_range(Float::NEG_INFINITY..=Float::INFINITY)?;
+    assert_eq!(
+        temp.get_bounds(),
+        (
+            Temperature::Celsius(Float::NEG_INFINITY),
+            Temperature::Celsius(Float::INFINITY)
+        )
+    );
+
+    // finite values below absolute zero are still rejected
+    assert!(matches!(
+        temp.set_lower_bound(-300.0),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn bounds_valid_by_default() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    assert!(temp.bounds_valid());
+
+    temp.set_bounds(-273.15, 100.0)?;
+    assert!(temp.bounds_valid());
+
+    // repairing valid bounds does nothing
+    temp.repair_bounds();
+    let (lower, upper) = temp.get_bounds();
+    assert_eq!(lower, Temperature::Celsius(-273.15));
+    assert_eq!(upper, Temperature::Celsius(100.0));
+
+    Ok(())
+}
+
+#[test]
+fn repair_bounds_after_drift() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    temp.set_bounds(-459.67, 100.0)?;
+
+    let mut drifted = false;
+
+    // bounce between units, fixing things whenever they drift
+    for _ in 0..50 {
+        for unit in [Unit::Kelvin, Unit::Celsius, Unit::Fahrenheit] {
+            temp = match unit {
+                Unit::Fahrenheit => temp.to_fahrenheit()?,
+                Unit::Celsius => temp.to_celsius()?,
+                Unit::Kelvin => temp.to_kelvin()?,
+            };
+
+            if !temp.bounds_valid() {
+                drifted = true;
+                temp.repair_bounds();
+
+                assert!(temp.bounds_valid());
+                assert_eq!(temp.get_bounds().0, temp.get_unchecked().absolute_zero());
+            }
+        }
+    }
+
+    // `f32` can't land exactly on absolute zero in every unit
+    assert!(drifted || cfg!(not(feature = "f32")));
+
+    // the upper bound survived all of that
+    assert_eq!(temp.get_unchecked().unit(), Unit::Fahrenheit);
+    assert_approx_eq!(temp.get_bounds().1.into_inner(), 100.0, 1e-2);
+
+    Ok(())
+}
+
+#[test]
+fn iter_range() -> anyhow::Result<()> {
+    let mut temp = CheckedTemperature::new(Temperature::Fahrenheit(70.0))?;
+    temp.set_bounds(60.0, 80.0)?;
+
+    let sweep: Vec<CheckedTemperature> = temp.iter_range(2.5).collect();
+    assert_eq!(sweep.len(), 9);
+
+    for (i, t) in sweep.iter().enumerate() {
+        assert_approx_eq!(t.get_inner(), 60.0 + i as Float * 2.5);
+        assert_eq!(t.get_unchecked().unit(), Unit::Fahrenheit);
+
+        // same bounds as the original
+        assert_eq!(t.get_bounds(), temp.get_bounds());
+    }
+
+    // a step that doesn't divide evenly stops before the upper bound
+    let uneven: Vec<Float> = temp.iter_range(7.0).map(|t| t.into_inner()).collect();
+    assert_eq!(uneven, [60.0, 67.0, 74.0]);
+
+    Ok(())
+}
+
+#[test]
+fn iter_range_empty() -> anyhow::Result<()> {
+    // no bounds at all
+    let temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    assert_eq!(temp.iter_range(1.0).count(), 0);
+
+    // only one bound
+    let mut temp = CheckedTemperature::new(Temperature::Celsius(20.0))?;
+    temp.set_lower_bound(0.0)?;
+    assert_eq!(temp.iter_range(1.0).count(), 0);
+
+    // bad steps
+    temp.set_upper_bound(10.0)?;
+    assert_eq!(temp.iter_range(0.0).count(), 0);
+    assert_eq!(temp.iter_range(-1.0).count(), 0);
+    assert_eq!(temp.iter_range(Float::NAN).count(), 0);
+
+    // a single point is a single item
+    temp.set_bounds(5.0, 5.0)?;
+    assert_eq!(temp.iter_range(1.0).count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn new_with_tolerance() -> anyhow::Result<()> {
+    // just below absolute zero, but past what the float can round away
+    #[cfg(not(feature = "f32"))]
+    let (noisy, epsilon): (Float, Float) = (-273.1500001, 1e-6);
+    #[cfg(feature = "f32")]
+    let (noisy, epsilon): (Float, Float) = (-273.1501, 1e-3);
+
+    let noisy = Temperature::Celsius(noisy);
+
+    assert!(matches!(
+        CheckedTemperature::new(noisy),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+
+    let snapped = CheckedTemperature::new_with_tolerance(noisy, epsilon)?;
+    assert_eq!(snapped.get_unchecked(), Temperature::Celsius(-273.15));
+
+    // too far below is still an error
+    assert!(matches!(
+        CheckedTemperature::new_with_tolerance(Temperature::Celsius(-274.0), epsilon),
+        Err(CheckedTempError::BelowAbsoluteZero(_))
+    ));
+
+    // valid temperatures go through untouched
+    let fine = CheckedTemperature::new_with_tolerance(Temperature::Fahrenheit(70.0), epsilon)?;
+    assert_eq!(fine.get_unchecked(), Temperature::Fahrenheit(70.0));
+
+    // other problems aren't forgiven
+    assert!(matches!(
+        CheckedTemperature::new_with_tolerance(Temperature::Kelvin(Float::NAN), epsilon),
+        Err(CheckedTempError::GivenValueIsNan)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use simmer::{
+    comfort::{ComfortThresholds, ComfortZone},
+    Temperature,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn default_boundaries() {
+    let cases = [
+        (15.9, ComfortZone::Cold),
+        (16.0, ComfortZone::Cool),
+        (19.9, ComfortZone::Cool),
+        (20.0, ComfortZone::Comfortable),
+        (23.9, ComfortZone::Comfortable),
+        (24.0, ComfortZone::Warm),
+        (27.9, ComfortZone::Warm),
+        (28.0, ComfortZone::Hot),
+        (40.0, ComfortZone::Hot),
+    ];
+
+    for (celsius, zone) in cases {
+        assert_eq!(Temperature::Celsius(celsius).comfort_zone(), zone);
+    }
+}
+
+#[test]
+fn other_units() {
+    // 50 F is 10 C
+    assert_eq!(
+        Temperature::Fahrenheit(50.0).comfort_zone(),
+        ComfortZone::Cold
+    );
+    // 295.15 K is 22 C
+    assert_eq!(
+        Temperature::Kelvin(295.15).comfort_zone(),
+        ComfortZone::Comfortable
+    );
+}
+
+#[test]
+fn custom_thresholds() {
+    let thresholds = ComfortThresholds::new(
+        Temperature::Celsius(10.0),
+        Temperature::Celsius(15.0),
+        Temperature::Celsius(30.0),
+        Temperature::Celsius(35.0),
+    );
+
+    assert_eq!(
+        Temperature::Celsius(9.0).comfort_zone_with(&thresholds),
+        ComfortZone::Cold
+    );
+    assert_eq!(
+        Temperature::Celsius(10.0).comfort_zone_with(&thresholds),
+        ComfortZone::Cool
+    );
+    assert_eq!(
+        Temperature::Celsius(25.0).comfort_zone_with(&thresholds),
+        ComfortZone::Comfortable
+    );
+    assert_eq!(
+        Temperature::Celsius(30.0).comfort_zone_with(&thresholds),
+        ComfortZone::Warm
+    );
+    assert_eq!(
+        Temperature::Celsius(35.0).comfort_zone_with(&thresholds),
+        ComfortZone::Hot
+    );
+}
+
+#[test]
+fn default_thresholds_match() {
+    let temp = Temperature::Celsius(21.0);
+
+    assert_eq!(
+        temp.comfort_zone(),
+        temp.comfort_zone_with(&ComfortThresholds::default())
+    );
+}
+
+#[test]
+fn zones_are_ordered() {
+    assert!(ComfortZone::Cold < ComfortZone::Cool);
+    assert!(ComfortZone::Warm < ComfortZone::Hot);
+}
+
+#[test]
+fn nan_is_cold() {
+    assert_eq!(
+        Temperature::Celsius(Float::NAN).comfort_zone(),
+        ComfortZone::Cold
+    );
+}
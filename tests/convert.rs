@@ -0,0 +1,157 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{
+    convert::{self, apply_coeffs, convert_array, convert_raw_slice, linear_coeffs},
+    Temperature, Unit,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+const UNITS: [Unit; 3] = [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin];
+
+/// Converts with the regular `Temperature` methods.
+fn convert_with_methods(value: Float, from: Unit, to: Unit) -> Float {
+    let temp = Temperature::from((value, from));
+
+    match to {
+        Unit::Fahrenheit => temp.to_fahrenheit(),
+        Unit::Celsius => temp.to_celsius(),
+        Unit::Kelvin => temp.to_kelvin(),
+    }
+    .into_inner()
+}
+
+#[test]
+fn coeffs_match_conversions() {
+    for from in UNITS {
+        for to in UNITS {
+            let coeffs = linear_coeffs(from, to);
+
+            for value in [-459.67, -40.0, 0.0, 32.0, 37.0, 100.0, 273.15, 5778.0] {
+                assert_approx_eq!(
+                    apply_coeffs(value, coeffs),
+                    convert_with_methods(value, from, to),
+                    1e-3
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn same_unit_coeffs_are_identity() {
+    for unit in UNITS {
+        assert_eq!(linear_coeffs(unit, unit), (1.0, 0.0));
+    }
+}
+
+#[test]
+fn fahrenheit_to_celsius() {
+    assert_approx_eq!(convert::fahrenheit_to_celsius(32.0), 0.0);
+    assert_approx_eq!(convert::fahrenheit_to_celsius(212.0), 100.0);
+    assert_approx_eq!(convert::fahrenheit_to_celsius(-40.0), -40.0);
+}
+
+#[test]
+fn fahrenheit_to_kelvin() {
+    assert_approx_eq!(convert::fahrenheit_to_kelvin(-459.67), 0.0, 1e-4);
+    assert_approx_eq!(convert::fahrenheit_to_kelvin(32.0), 273.15, 1e-4);
+    assert_approx_eq!(convert::fahrenheit_to_kelvin(212.0), 373.15, 1e-4);
+}
+
+#[test]
+fn celsius_to_fahrenheit() {
+    assert_approx_eq!(convert::celsius_to_fahrenheit(0.0), 32.0);
+    assert_approx_eq!(convert::celsius_to_fahrenheit(100.0), 212.0);
+    assert_approx_eq!(convert::celsius_to_fahrenheit(-40.0), -40.0);
+}
+
+#[test]
+fn celsius_to_kelvin() {
+    assert_approx_eq!(convert::celsius_to_kelvin(-273.15), 0.0, 1e-4);
+    assert_approx_eq!(convert::celsius_to_kelvin(0.0), 273.15, 1e-4);
+    assert_approx_eq!(convert::celsius_to_kelvin(100.0), 373.15, 1e-4);
+}
+
+#[test]
+fn kelvin_to_fahrenheit() {
+    assert_approx_eq!(convert::kelvin_to_fahrenheit(0.0), -459.67, 1e-4);
+    assert_approx_eq!(convert::kelvin_to_fahrenheit(273.15), 32.0, 1e-4);
+    assert_approx_eq!(convert::kelvin_to_fahrenheit(373.15), 212.0, 1e-4);
+}
+
+#[test]
+fn kelvin_to_celsius() {
+    assert_approx_eq!(convert::kelvin_to_celsius(0.0), -273.15, 1e-4);
+    assert_approx_eq!(convert::kelvin_to_celsius(273.15), 0.0, 1e-4);
+    assert_approx_eq!(convert::kelvin_to_celsius(373.15), 100.0, 1e-4);
+}
+
+#[test]
+fn raw_slice_matches_conversions() {
+    let values: [Float; 6] = [-459.67, -40.0, 0.0, 37.0, 100.0, 5778.0];
+
+    for from in UNITS {
+        for to in UNITS {
+            let mut out = [0.0; 6];
+            convert_raw_slice(&values, from, to, &mut out);
+
+            for (value, converted) in values.iter().zip(out) {
+                assert_approx_eq!(converted, convert_with_methods(*value, from, to), 1e-3);
+            }
+        }
+    }
+}
+
+#[test]
+fn raw_slice_empty() {
+    let mut out: [Float; 0] = [];
+    convert_raw_slice(&[], Unit::Celsius, Unit::Kelvin, &mut out);
+}
+
+#[test]
+#[should_panic]
+fn raw_slice_length_mismatch() {
+    let mut out = [0.0; 2];
+    convert_raw_slice(&[1.0, 2.0, 3.0], Unit::Celsius, Unit::Kelvin, &mut out);
+}
+
+/// Built at compile time!
+const TABLE_K: [Temperature; 4] =
+    convert_array([-40.0, 32.0, 98.6, 212.0], Unit::Fahrenheit, Unit::Kelvin);
+
+#[test]
+fn const_table() {
+    assert!(TABLE_K.iter().all(|t| t.unit() == Unit::Kelvin));
+
+    assert_approx_eq!(TABLE_K[0].into_inner(), 233.15, 1e-3);
+    assert_approx_eq!(TABLE_K[1].into_inner(), 273.15, 1e-3);
+    assert_approx_eq!(TABLE_K[2].into_inner(), 310.15, 1e-3);
+    assert_approx_eq!(TABLE_K[3].into_inner(), 373.15, 1e-3);
+}
+
+#[test]
+fn convert_array_matches_temperature() {
+    let values: [Float; 5] = [-273.15, -40.0, 0.0, 21.5, 1000.0];
+
+    for to in [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin] {
+        let converted = convert_array(values, Unit::Celsius, to);
+
+        for (value, temp) in values.iter().zip(converted) {
+            let expected = Temperature::Celsius(*value).to_unit(to);
+
+            assert_eq!(temp.unit(), to);
+            assert_approx_eq!(temp.into_inner(), expected.into_inner(), 1e-3);
+        }
+    }
+
+    // empty arrays are fine, too
+    assert_eq!(
+        convert_array([], Unit::Kelvin, Unit::Celsius),
+        [] as [Temperature; 0]
+    );
+}
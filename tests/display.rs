@@ -0,0 +1,159 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{Temperature, Unit};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn friendly_everyday() {
+    let room = Temperature::Celsius(21.456);
+
+    assert_eq!(room.display_friendly(Unit::Celsius).to_string(), "21.5°C");
+    assert_eq!(
+        room.display_friendly(Unit::Fahrenheit).to_string(),
+        "70.6°F"
+    );
+    assert_eq!(room.display_friendly(Unit::Kelvin).to_string(), "294.61 K");
+
+    let freezer = Temperature::Fahrenheit(-4.0);
+    assert_eq!(
+        freezer.display_friendly(Unit::Celsius).to_string(),
+        "-20.0°C"
+    );
+}
+
+#[test]
+fn friendly_near_absolute_zero() {
+    let almost = Temperature::Kelvin(0.01);
+    assert_eq!(almost.display_friendly(Unit::Celsius).to_string(), "0.01 K");
+    assert_eq!(
+        almost.display_friendly(Unit::Fahrenheit).to_string(),
+        "0.01 K"
+    );
+
+    // invalid temperatures also fall back to kelvin
+    let invalid = Temperature::Celsius(-274.15);
+    assert_eq!(
+        invalid.display_friendly(Unit::Fahrenheit).to_string(),
+        "-1.00 K"
+    );
+}
+
+#[test]
+fn debug_verbose() {
+    assert_eq!(
+        format!("{:?}", Temperature::Celsius(25.0).debug_verbose()),
+        "Celsius(25.0) [298.15 K]"
+    );
+    assert_eq!(
+        format!("{:?}", Temperature::Fahrenheit(32.0).debug_verbose()),
+        "Fahrenheit(32.0) [273.15 K]"
+    );
+    assert_eq!(
+        format!("{:?}", Temperature::Kelvin(300.0).debug_verbose()),
+        "Kelvin(300.0) [300.0 K]"
+    );
+}
+
+#[test]
+fn debug_verbose_keeps_derived_debug() {
+    let temp = Temperature::Celsius(25.0);
+
+    assert_eq!(format!("{temp:?}"), "Celsius(25.0)");
+    assert!(format!("{:?}", temp.debug_verbose()).starts_with(&format!("{temp:?}")));
+}
+
+#[test]
+fn split_degrees_positive() {
+    let (whole, fraction) = Temperature::Celsius(21.75).split_degrees();
+    assert_eq!(whole, 21);
+    assert_approx_eq!(fraction, 0.75);
+
+    let (whole, fraction) = Temperature::Fahrenheit(98.0).split_degrees();
+    assert_eq!(whole, 98);
+    assert_eq!(fraction, 0.0);
+}
+
+#[test]
+fn split_degrees_negative() {
+    // the fraction keeps the sign
+    let (whole, fraction) = Temperature::Celsius(-3.75).split_degrees();
+    assert_eq!(whole, -3);
+    assert_approx_eq!(fraction, -0.75);
+
+    let (whole, fraction) = Temperature::Fahrenheit(-0.5).split_degrees();
+    assert_eq!(whole, 0);
+    assert_approx_eq!(fraction, -0.5);
+}
+
+#[test]
+fn split_degrees_keeps_unit() {
+    // no conversion happens
+    let (whole, _) = Temperature::Kelvin(273.15).split_degrees();
+    assert_eq!(whole, 273);
+}
+
+#[test]
+fn split_degrees_nan() {
+    let (whole, fraction) = Temperature::Celsius(Float::NAN).split_degrees();
+
+    assert_eq!(whole, 0);
+    assert!(fraction.is_nan());
+}
+
+#[test]
+fn bcd_positive() {
+    assert_eq!(
+        Temperature::Celsius(21.0).to_bcd::<4>(),
+        Some(([0, 0, 2, 1], false))
+    );
+    assert_eq!(
+        Temperature::Fahrenheit(98.6).to_bcd::<3>(),
+        Some(([0, 9, 8], false))
+    );
+    assert_eq!(
+        Temperature::Kelvin(373.15).to_bcd::<3>(),
+        Some(([3, 7, 3], false))
+    );
+    assert_eq!(
+        Temperature::Celsius(0.0).to_bcd::<2>(),
+        Some(([0, 0], false))
+    );
+}
+
+#[test]
+fn bcd_negative() {
+    assert_eq!(
+        Temperature::Celsius(-40.0).to_bcd::<3>(),
+        Some(([0, 4, 0], true))
+    );
+    assert_eq!(
+        Temperature::Fahrenheit(-459.67).to_bcd::<3>(),
+        Some(([4, 5, 9], true))
+    );
+
+    // no "negative zero" on the display
+    assert_eq!(Temperature::Celsius(-0.5).to_bcd::<1>(), Some(([0], false)));
+}
+
+#[test]
+fn bcd_oversized_and_invalid() {
+    assert_eq!(Temperature::Celsius(100.0).to_bcd::<2>(), None);
+    assert_eq!(Temperature::Celsius(-100.0).to_bcd::<2>(), None);
+    assert_eq!(
+        Temperature::Celsius(99.9).to_bcd::<2>(),
+        Some(([9, 9], false))
+    );
+
+    assert_eq!(Temperature::Celsius(Float::NAN).to_bcd::<3>(), None);
+    assert_eq!(Temperature::Celsius(Float::INFINITY).to_bcd::<3>(), None);
+    assert_eq!(Temperature::Celsius(1e30).to_bcd::<30>(), None);
+
+    // zero digits only fits zero
+    assert_eq!(Temperature::Celsius(0.4).to_bcd::<0>(), Some(([], false)));
+    assert_eq!(Temperature::Celsius(1.0).to_bcd::<0>(), None);
+}
@@ -0,0 +1,123 @@
+use simmer::{ParseTemperatureError, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn plain() {
+    assert_eq!("25 C".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("98.6F".parse(), Ok(Temperature::Fahrenheit(98.6)));
+    assert_eq!("273.15 K".parse(), Ok(Temperature::Kelvin(273.15)));
+    assert_eq!("-40 F".parse(), Ok(Temperature::Fahrenheit(-40.0)));
+    assert_eq!("1.5e2 K".parse(), Ok(Temperature::Kelvin(150.0)));
+}
+
+#[test]
+fn degree_symbols() {
+    assert_eq!("25 °C".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("98.6°F".parse(), Ok(Temperature::Fahrenheit(98.6)));
+    assert_eq!("-3.5° C".parse(), Ok(Temperature::Celsius(-3.5)));
+
+    // lookalikes
+    assert_eq!("25ºC".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("25 ˚C".parse(), Ok(Temperature::Celsius(25.0)));
+
+    // single-character units
+    assert_eq!("25℃".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("77 ℉".parse(), Ok(Temperature::Fahrenheit(77.0)));
+    assert_eq!("300\u{212a}".parse(), Ok(Temperature::Kelvin(300.0)));
+}
+
+#[test]
+fn unicode_whitespace() {
+    // non-breaking, narrow non-breaking, and thin spaces
+    for space in ['\u{a0}', '\u{202f}', '\u{2009}'] {
+        let input = format!("{space}25{space}°C{space}");
+        assert_eq!(input.parse(), Ok(Temperature::Celsius(25.0)), "{input:?}");
+    }
+
+    assert_eq!(
+        "\t 98.6\u{a0}°\u{a0}F \n".parse(),
+        Ok(Temperature::Fahrenheit(98.6))
+    );
+}
+
+#[test]
+fn mixed_casing() {
+    assert_eq!("25 c".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("25 °c".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("25 Celsius".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("25 cElSiUs".parse(), Ok(Temperature::Celsius(25.0)));
+    assert_eq!("212 FAHRENHEIT".parse(), Ok(Temperature::Fahrenheit(212.0)));
+    assert_eq!("0 kelvin".parse(), Ok(Temperature::Kelvin(0.0)));
+    assert_eq!("0k".parse(), Ok(Temperature::Kelvin(0.0)));
+}
+
+#[test]
+fn missing_parts() {
+    assert_eq!(
+        "".parse::<Temperature>(),
+        Err(ParseTemperatureError::MissingField("value"))
+    );
+    assert_eq!(
+        "\u{a0}°C".parse::<Temperature>(),
+        Err(ParseTemperatureError::MissingField("value"))
+    );
+    assert_eq!(
+        "25".parse::<Temperature>(),
+        Err(ParseTemperatureError::MissingField("unit"))
+    );
+    assert_eq!(
+        "25 °".parse::<Temperature>(),
+        Err(ParseTemperatureError::MissingField("unit"))
+    );
+}
+
+#[test]
+fn bad_parts() {
+    for input in ["1.2.3 C", "--5 C", "e5 C", "+ C"] {
+        assert_eq!(
+            input.parse::<Temperature>(),
+            Err(ParseTemperatureError::InvalidNumber),
+            "{input:?}"
+        );
+    }
+
+    for input in [
+        "25 R",
+        "25 °Rankine",
+        "25 C C",
+        "25 C°",
+        "25 degrees C",
+        "NaN C",
+    ] {
+        assert!(
+            matches!(
+                input.parse::<Temperature>(),
+                Err(ParseTemperatureError::UnknownUnit | ParseTemperatureError::MissingField(_))
+            ),
+            "{input:?}"
+        );
+    }
+    assert_eq!(
+        "25 R".parse::<Temperature>(),
+        Err(ParseTemperatureError::UnknownUnit)
+    );
+}
+
+#[test]
+fn round_trips_display() {
+    for temp in [
+        Temperature::Celsius(21.5),
+        Temperature::Fahrenheit(-40.0),
+        Temperature::Kelvin(1e-3),
+        Temperature::Celsius(Float::MAX),
+    ] {
+        let input = format!("{temp} {}", temp.unit());
+        assert_eq!(input.parse(), Ok(temp));
+    }
+}
@@ -0,0 +1,405 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{Temperature, TemperatureIteratorExt};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+fn readings() -> Vec<Temperature> {
+    vec![
+        Temperature::Celsius(20.0),
+        Temperature::Fahrenheit(50.0), // 10 C
+        Temperature::Kelvin(303.15),   // 30 C
+    ]
+}
+
+#[test]
+fn mean() {
+    let mean = readings().into_iter().mean_temperature().unwrap();
+
+    assert!(matches!(mean, Temperature::Kelvin(_)));
+    assert_approx_eq!(mean.to_celsius().into_inner(), 20.0, 1e-4);
+}
+
+#[test]
+fn max_and_min() {
+    assert_eq!(
+        readings().into_iter().max_temperature(),
+        Some(Temperature::Kelvin(303.15))
+    );
+    assert_eq!(
+        readings().into_iter().min_temperature(),
+        Some(Temperature::Fahrenheit(50.0))
+    );
+}
+
+#[test]
+fn lazy_chains() {
+    // only look at the readings above 15 C
+    let warm_mean = readings()
+        .into_iter()
+        .filter(|t| t.to_celsius().into_inner() > 15.0)
+        .mean_temperature()
+        .unwrap();
+
+    assert_approx_eq!(warm_mean.to_celsius().into_inner(), 25.0, 1e-4);
+}
+
+#[test]
+fn empty_and_nan() {
+    assert_eq!(Vec::new().into_iter().mean_temperature(), None);
+    assert_eq!(Vec::new().into_iter().max_temperature(), None);
+    assert_eq!(Vec::new().into_iter().min_temperature(), None);
+
+    let with_nan = vec![Temperature::Celsius(Float::NAN), Temperature::Celsius(1.0)];
+    assert_eq!(
+        with_nan.clone().into_iter().max_temperature(),
+        Some(Temperature::Celsius(1.0))
+    );
+    assert_eq!(
+        with_nan.into_iter().min_temperature(),
+        Some(Temperature::Celsius(1.0))
+    );
+}
+
+#[test]
+fn robust_mean_excludes_outliers() {
+    let readings = [
+        Temperature::Celsius(20.0),
+        Temperature::Fahrenheit(-1000.0), // below abs. zero!
+        Temperature::Celsius(24.0),
+        Temperature::Kelvin(10_000.0),
+        Temperature::Celsius(Float::NAN),
+    ];
+
+    let mean = Temperature::robust_mean(
+        &readings,
+        Temperature::Celsius(0.0),
+        Temperature::Celsius(50.0),
+    )
+    .unwrap();
+
+    assert!(matches!(mean, Temperature::Kelvin(_)));
+    assert_approx_eq!(mean.to_celsius().into_inner(), 22.0, 1e-4);
+}
+
+#[test]
+fn robust_mean_bounds_are_inclusive() {
+    let readings = [Temperature::Celsius(0.0), Temperature::Celsius(10.0)];
+
+    let mean = Temperature::robust_mean(
+        &readings,
+        Temperature::Celsius(0.0),
+        Temperature::Celsius(10.0),
+    )
+    .unwrap();
+    assert_approx_eq!(mean.to_celsius().into_inner(), 5.0, 1e-4);
+}
+
+#[test]
+fn robust_mean_nothing_qualifies() {
+    let lower = Temperature::Celsius(0.0);
+    let upper = Temperature::Celsius(10.0);
+
+    assert_eq!(
+        Temperature::robust_mean(&[Temperature::Celsius(50.0)], lower, upper),
+        None
+    );
+    assert_eq!(Temperature::robust_mean(&[], lower, upper), None);
+}
+
+#[test]
+fn time_weighted_mean_trapezoidal() {
+    let samples = [
+        (Temperature::Kelvin(300.0), 0.0),
+        (Temperature::Kelvin(310.0), 2.0),
+        (Temperature::Kelvin(310.0), 3.0),
+        (Temperature::Kelvin(290.0), 7.0),
+    ];
+
+    // by hand:
+    //   (300 + 310) / 2 * 2 = 610
+    //   (310 + 310) / 2 * 1 = 310
+    //   (310 + 290) / 2 * 4 = 1200
+    //   (610 + 310 + 1200) / 7 = 302.857...
+    let mean = Temperature::time_weighted_mean(&samples).unwrap();
+    assert_approx_eq!(mean.into_inner(), 2120.0 / 7.0, 1e-3);
+}
+
+#[test]
+fn time_weighted_mean_uses_first_unit() {
+    let samples = [
+        (Temperature::Celsius(10.0), 100.0),
+        (Temperature::Kelvin(303.15), 101.0), // 30 C
+    ];
+
+    let mean = Temperature::time_weighted_mean(&samples).unwrap();
+
+    assert!(matches!(mean, Temperature::Celsius(_)));
+    assert_approx_eq!(mean.into_inner(), 20.0, 1e-3);
+}
+
+#[test]
+fn time_weighted_mean_needs_good_samples() {
+    let t = Temperature::Celsius(20.0);
+
+    // too few
+    assert_eq!(Temperature::time_weighted_mean(&[]), None);
+    assert_eq!(Temperature::time_weighted_mean(&[(t, 0.0)]), None);
+
+    // unsorted
+    assert_eq!(
+        Temperature::time_weighted_mean(&[(t, 5.0), (t, 1.0), (t, 10.0)]),
+        None
+    );
+
+    // no time passed
+    assert_eq!(Temperature::time_weighted_mean(&[(t, 1.0), (t, 1.0)]), None);
+}
+
+#[test]
+fn time_weighted_mean_non_finite_timestamps() {
+    let t = Temperature::Celsius(20.0);
+
+    for bad in [Float::NAN, Float::INFINITY, Float::NEG_INFINITY] {
+        // anywhere in the list: first, middle, or last
+        assert_eq!(
+            Temperature::time_weighted_mean(&[(t, bad), (t, 1.0), (t, 2.0)]),
+            None
+        );
+        assert_eq!(
+            Temperature::time_weighted_mean(&[(t, 0.0), (t, bad), (t, 2.0)]),
+            None
+        );
+        assert_eq!(
+            Temperature::time_weighted_mean(&[(t, 0.0), (t, 1.0), (t, bad)]),
+            None
+        );
+    }
+
+    // a `NaN` reading is different: it's still averaged in
+    let nan = Temperature::Celsius(Float::NAN);
+    assert!(Temperature::time_weighted_mean(&[(t, 0.0), (nan, 1.0)])
+        .unwrap()
+        .is_nan());
+}
+
+#[test]
+fn reduce_kelvin_sum() {
+    let sum = Temperature::reduce_kelvin(&readings(), 0.0, |acc, k| acc + k).unwrap();
+
+    assert!(matches!(sum, Temperature::Kelvin(_)));
+    assert_approx_eq!(sum.into_inner(), 293.15 + 283.15 + 303.15, 1e-3);
+}
+
+#[test]
+fn reduce_kelvin_max() {
+    let max = Temperature::reduce_kelvin(&readings(), Float::NEG_INFINITY, Float::max).unwrap();
+    assert_approx_eq!(max.into_inner(), 303.15, 1e-4);
+}
+
+#[test]
+fn reduce_kelvin_empty() {
+    assert_eq!(Temperature::reduce_kelvin(&[], 0.0, |acc, k| acc + k), None);
+}
+
+#[test]
+fn coldest_and_hottest_keep_units() {
+    // -20 F is about -28.9 C, the coldest of the bunch
+    let readings = [
+        Temperature::Celsius(-25.0),
+        Temperature::Fahrenheit(-20.0),
+        Temperature::Kelvin(250.0),
+        Temperature::Fahrenheit(100.0),
+        Temperature::Celsius(37.0),
+    ];
+
+    assert_eq!(
+        Temperature::coldest(&readings),
+        Some(Temperature::Fahrenheit(-20.0))
+    );
+    assert_eq!(
+        Temperature::hottest(&readings),
+        Some(Temperature::Fahrenheit(100.0))
+    );
+}
+
+#[test]
+fn coldest_and_hottest_edge_cases() {
+    assert_eq!(Temperature::coldest(&[]), None);
+    assert_eq!(Temperature::hottest(&[]), None);
+
+    let nan = [Temperature::Celsius(Float::NAN)];
+    assert_eq!(Temperature::coldest(&nan), None);
+
+    // physically equal, so the first one wins
+    let tied = [Temperature::Celsius(0.0), Temperature::Kelvin(273.15)];
+    assert_eq!(Temperature::coldest(&tied), Some(Temperature::Celsius(0.0)));
+    assert_eq!(Temperature::hottest(&tied), Some(Temperature::Celsius(0.0)));
+}
+
+#[test]
+fn retain_finite_compacts() {
+    let mut buffer = [
+        Temperature::Celsius(Float::NAN),
+        Temperature::Celsius(20.0),
+        Temperature::Fahrenheit(Float::INFINITY),
+        Temperature::Kelvin(300.0),
+        Temperature::Celsius(Float::NEG_INFINITY),
+        Temperature::Fahrenheit(70.0),
+        Temperature::Kelvin(Float::NAN),
+    ];
+
+    let len = Temperature::retain_finite(&mut buffer);
+
+    assert_eq!(len, 3);
+    assert_eq!(
+        buffer[..len],
+        [
+            Temperature::Celsius(20.0),
+            Temperature::Kelvin(300.0),
+            Temperature::Fahrenheit(70.0),
+        ]
+    );
+
+    // the leftovers are all garbage
+    assert!(buffer[len..].iter().all(|t| !t.get_inner().is_finite()));
+
+    // ready for averaging
+    let mean = buffer[..len].iter().copied().mean_temperature().unwrap();
+    assert!(mean.get_inner().is_finite());
+}
+
+#[test]
+fn retain_finite_edges() {
+    let mut empty: [Temperature; 0] = [];
+    assert_eq!(Temperature::retain_finite(&mut empty), 0);
+
+    let mut all_good = [Temperature::Celsius(1.0), Temperature::Celsius(2.0)];
+    assert_eq!(Temperature::retain_finite(&mut all_good), 2);
+    assert_eq!(
+        all_good,
+        [Temperature::Celsius(1.0), Temperature::Celsius(2.0)]
+    );
+
+    let mut all_bad = [Temperature::Celsius(Float::NAN); 4];
+    assert_eq!(Temperature::retain_finite(&mut all_bad), 0);
+}
+
+#[test]
+fn dedup_physical_collapses_units() {
+    let mut readings = [
+        Temperature::Celsius(0.0),
+        Temperature::Fahrenheit(32.0),
+        Temperature::Kelvin(273.15),
+        Temperature::Celsius(100.0),
+        Temperature::Fahrenheit(212.0),
+        Temperature::Celsius(37.0),
+    ];
+
+    let len = Temperature::dedup_physical(&mut readings, 1e-3);
+
+    assert_eq!(len, 3);
+    assert_eq!(
+        readings[..len],
+        [
+            Temperature::Celsius(0.0),
+            Temperature::Celsius(100.0),
+            Temperature::Celsius(37.0),
+        ]
+    );
+}
+
+#[test]
+fn dedup_physical_epsilon() {
+    let mut readings = [
+        Temperature::Kelvin(300.0),
+        Temperature::Kelvin(300.05),
+        Temperature::Kelvin(300.5),
+    ];
+
+    // tight enough to keep everything
+    assert_eq!(Temperature::dedup_physical(&mut readings, 0.01), 3);
+
+    // loose enough to drop the close one
+    let len = Temperature::dedup_physical(&mut readings, 0.1);
+    assert_eq!(len, 2);
+    assert_eq!(
+        readings[..len],
+        [Temperature::Kelvin(300.0), Temperature::Kelvin(300.5)]
+    );
+}
+
+#[test]
+fn dedup_physical_edges() {
+    let mut empty: [Temperature; 0] = [];
+    assert_eq!(Temperature::dedup_physical(&mut empty, 1.0), 0);
+
+    // `NaN` is never a duplicate, even of itself
+    let mut nans = [Temperature::Celsius(Float::NAN); 3];
+    assert_eq!(Temperature::dedup_physical(&mut nans, Float::INFINITY), 3);
+}
+
+#[test]
+fn monotonic_sorted_tables() {
+    // a thermocouple table, written in whatever units were handy
+    let table = [
+        Temperature::Kelvin(0.0),
+        Temperature::Fahrenheit(-40.0),
+        Temperature::Celsius(0.0),
+        Temperature::Fahrenheit(212.0),
+        Temperature::Kelvin(1000.0),
+    ];
+
+    assert!(Temperature::is_monotonic_increasing(&table));
+    assert!(Temperature::is_sorted_physical(&table));
+
+    // trivially sorted
+    assert!(Temperature::is_monotonic_increasing(&[]));
+    assert!(Temperature::is_sorted_physical(&table[..1]));
+}
+
+#[test]
+fn monotonic_unsorted_tables() {
+    // the raw values increase, but physically 50 °F is colder than 20 °C
+    let misordered = [
+        Temperature::Celsius(0.0),
+        Temperature::Celsius(20.0),
+        Temperature::Fahrenheit(50.0),
+    ];
+
+    assert!(!Temperature::is_monotonic_increasing(&misordered));
+    assert!(!Temperature::is_sorted_physical(&misordered));
+
+    let backwards = [Temperature::Kelvin(300.0), Temperature::Kelvin(299.0)];
+    assert!(!Temperature::is_sorted_physical(&backwards));
+}
+
+#[test]
+fn monotonic_equal_elements() {
+    let repeated = [
+        Temperature::Kelvin(250.0),
+        Temperature::Kelvin(300.0),
+        Temperature::Kelvin(300.0),
+        Temperature::Kelvin(350.0),
+    ];
+
+    // equal neighbors are sorted, but not strictly increasing
+    assert!(!Temperature::is_monotonic_increasing(&repeated));
+    assert!(Temperature::is_sorted_physical(&repeated));
+}
+
+#[test]
+fn monotonic_nan() {
+    let with_nan = [
+        Temperature::Celsius(0.0),
+        Temperature::Celsius(Float::NAN),
+        Temperature::Celsius(10.0),
+    ];
+
+    assert!(!Temperature::is_monotonic_increasing(&with_nan));
+    assert!(!Temperature::is_sorted_physical(&with_nan));
+}
@@ -0,0 +1,222 @@
+use simmer::{ParseTemperatureError, Temperature};
+use util::CharArrWriter;
+
+extern crate alloc;
+
+mod util;
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+/// Writes `temp` as JSON and returns the output.
+fn json(temp: Temperature) -> alloc::string::String {
+    let mut buf = CharArrWriter::default();
+
+    ufmt::uwrite!(&mut buf, "{}", temp.to_json()).unwrap();
+
+    buf.to_char_iter()
+        .copied()
+        .collect::<alloc::string::String>()
+        .trim_end()
+        .into()
+}
+
+#[test]
+fn exact_output() {
+    assert_eq!(
+        json(Temperature::Celsius(25.0)),
+        r#"{"unit":"Celsius","value":25.0}"#
+    );
+    assert_eq!(
+        json(Temperature::Fahrenheit(98.25)),
+        r#"{"unit":"Fahrenheit","value":98.25}"#
+    );
+    assert_eq!(
+        json(Temperature::Kelvin(0.0)),
+        r#"{"unit":"Kelvin","value":0.0}"#
+    );
+}
+
+#[test]
+fn negative_values_keep_their_sign() {
+    assert_eq!(
+        json(Temperature::Celsius(-40.5)),
+        r#"{"unit":"Celsius","value":-40.5}"#
+    );
+    assert_eq!(
+        json(Temperature::Celsius(-0.25)),
+        r#"{"unit":"Celsius","value":-0.25}"#
+    );
+}
+
+#[test]
+fn rounds_to_five_decimals() {
+    assert_eq!(
+        json(Temperature::Kelvin(273.15)),
+        r#"{"unit":"Kelvin","value":273.15}"#
+    );
+    assert_eq!(
+        json(Temperature::Celsius(1.0 / 3.0)),
+        r#"{"unit":"Celsius","value":0.33333}"#
+    );
+    assert_eq!(
+        json(Temperature::Celsius(0.999_999)),
+        r#"{"unit":"Celsius","value":1.0}"#
+    );
+}
+
+#[test]
+fn huge_values_use_an_exponent() {
+    assert_eq!(
+        json(Temperature::Kelvin(1e20)),
+        r#"{"unit":"Kelvin","value":1.0e20}"#
+    );
+
+    let planck = json(Temperature::PLANCK_TEMPERATURE);
+    assert!(planck.starts_with(r#"{"unit":"Kelvin","value":1.4167"#));
+    assert!(planck.ends_with("e32}"));
+}
+
+#[test]
+fn non_finite_is_null() {
+    assert_eq!(
+        json(Temperature::Celsius(Float::NAN)),
+        r#"{"unit":"Celsius","value":null}"#
+    );
+    assert_eq!(
+        json(Temperature::Kelvin(Float::INFINITY)),
+        r#"{"unit":"Kelvin","value":null}"#
+    );
+}
+
+#[test]
+fn fits_in_64_bytes() {
+    for temp in [
+        Temperature::Fahrenheit(-Float::MAX),
+        Temperature::Fahrenheit(-9.9e13),
+        Temperature::Fahrenheit(-12_345.678),
+    ] {
+        assert!(json(temp).len() <= 64, "{}", json(temp));
+    }
+}
+
+#[test]
+fn parse_valid() {
+    assert_eq!(
+        Temperature::from_json(r#"{"unit":"Celsius","value":25.0}"#),
+        Ok(Temperature::Celsius(25.0))
+    );
+    assert_eq!(
+        Temperature::from_json(r#"{"value":-40,"unit":"Fahrenheit"}"#),
+        Ok(Temperature::Fahrenheit(-40.0))
+    );
+    assert_eq!(
+        Temperature::from_json(r#"{"unit":"Kelvin","value":1.5e2}"#),
+        Ok(Temperature::Kelvin(150.0))
+    );
+
+    // `null` is how `NaN` gets written
+    assert!(Temperature::from_json(r#"{"unit":"Kelvin","value":null}"#)
+        .unwrap()
+        .is_nan());
+}
+
+#[test]
+fn parse_tolerates_whitespace() {
+    let pretty = "
+        {
+            \"unit\" : \"Celsius\",
+            \"value\"\t:\t21.5
+        }
+    ";
+
+    assert_eq!(
+        Temperature::from_json(pretty),
+        Ok(Temperature::Celsius(21.5))
+    );
+}
+
+#[test]
+fn round_trip() {
+    for temp in [
+        Temperature::Celsius(25.0),
+        Temperature::Fahrenheit(-40.5),
+        Temperature::Kelvin(273.15),
+        Temperature::Celsius(-0.125),
+    ] {
+        assert_eq!(Temperature::from_json(&json(temp)), Ok(temp));
+    }
+}
+
+#[test]
+fn parse_malformed() {
+    for input in [
+        "",
+        "   ",
+        "25.0",
+        r#"["Celsius", 25.0]"#,
+        r#"{"unit":"Celsius","value":25.0"#,
+        r#"{"unit":"Celsius" "value":25.0}"#,
+        r#"{"unit" "Celsius","value":25.0}"#,
+        r#"{"unit":"Celsius","value":25.0,}"#,
+        r#"{"unit":"Celsius","value":25.0} trailing"#,
+        r#"{"unit":"Celsius","value":25.0,"extra":1}"#,
+        r#"{"unit":"Celsius","unit":"Kelvin","value":25.0}"#,
+        r#"{"unit":"Cel\"sius","value":25.0}"#,
+        r#"{unit:"Celsius","value":25.0}"#,
+    ] {
+        assert!(
+            matches!(
+                Temperature::from_json(input),
+                Err(ParseTemperatureError::Malformed(_))
+            ),
+            "{input} should be malformed, got {:?}",
+            Temperature::from_json(input)
+        );
+    }
+}
+
+#[test]
+fn parse_missing_fields() {
+    assert_eq!(
+        Temperature::from_json(r#"{"value":25.0}"#),
+        Err(ParseTemperatureError::MissingField("unit"))
+    );
+    assert_eq!(
+        Temperature::from_json(r#"{"unit":"Celsius"}"#),
+        Err(ParseTemperatureError::MissingField("value"))
+    );
+    assert_eq!(
+        Temperature::from_json("{}"),
+        Err(ParseTemperatureError::MissingField("unit"))
+    );
+}
+
+#[test]
+fn parse_unknown_unit() {
+    for unit in ["Rankine", "celsius", "C", ""] {
+        let input = format!(r#"{{"unit":"{unit}","value":25.0}}"#);
+
+        assert_eq!(
+            Temperature::from_json(&input),
+            Err(ParseTemperatureError::UnknownUnit)
+        );
+    }
+}
+
+#[test]
+fn parse_invalid_number() {
+    for value in ["\"25\"", "inf", "NaN", "1.2.3", "--5", "true", ""] {
+        let input = format!(r#"{{"unit":"Celsius","value":{value}}}"#);
+
+        assert_eq!(
+            Temperature::from_json(&input),
+            Err(ParseTemperatureError::InvalidNumber),
+            "{input}"
+        );
+    }
+}
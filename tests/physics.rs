@@ -0,0 +1,473 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{
+    physics::{gradient, STEFAN_BOLTZMANN, WIEN_DISPLACEMENT},
+    Temperature,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn gradient_rate() {
+    let g = gradient(Temperature::Celsius(100.0), Temperature::Celsius(0.0), 4.0);
+    assert_approx_eq!(g.rate_per_meter(), 25.0);
+
+    // mixed units use the true difference
+    let g = gradient(
+        Temperature::Kelvin(373.15),
+        Temperature::Fahrenheit(32.0),
+        4.0,
+    );
+    assert_approx_eq!(g.rate_per_meter(), 25.0, 1e-3);
+
+    // swapping the sides flips the sign
+    let g = gradient(Temperature::Celsius(0.0), Temperature::Celsius(100.0), 4.0);
+    assert_approx_eq!(g.rate_per_meter(), -25.0);
+
+    // negative distances are just distances
+    let g = gradient(Temperature::Celsius(100.0), Temperature::Celsius(0.0), -4.0);
+    assert_approx_eq!(g.distance(), 4.0);
+    assert_approx_eq!(g.rate_per_meter(), 25.0);
+}
+
+#[test]
+fn gradient_zero_distance() {
+    let same = gradient(Temperature::Celsius(10.0), Temperature::Celsius(10.0), 0.0);
+    assert_approx_eq!(same.rate_per_meter(), 0.0);
+
+    let hot = gradient(Temperature::Celsius(20.0), Temperature::Celsius(10.0), 0.0);
+    assert_eq!(hot.rate_per_meter(), Float::INFINITY);
+
+    let cold = gradient(Temperature::Celsius(10.0), Temperature::Celsius(20.0), 0.0);
+    assert_eq!(cold.rate_per_meter(), Float::NEG_INFINITY);
+}
+
+#[test]
+fn blackbody_power() {
+    // the sun's surface, ~63.2 MW/m^2
+    let sun = Temperature::Kelvin(5778.0);
+    let expected: Float = STEFAN_BOLTZMANN * 5778.0 * 5778.0 * 5778.0 * 5778.0;
+
+    assert_approx_eq!(sun.blackbody_power() / expected, 1.0, 1e-5);
+    assert_approx_eq!(sun.blackbody_power() / 6.3201e7, 1.0, 1e-3);
+
+    // same thing in celsius
+    let sun_c = Temperature::Celsius(5504.85);
+    assert_approx_eq!(sun_c.blackbody_power() / expected, 1.0, 1e-4);
+
+    // nothing radiates at (or below) absolute zero
+    assert_approx_eq!(Temperature::Kelvin(0.0).blackbody_power(), 0.0);
+    assert_approx_eq!(Temperature::Kelvin(-10.0).blackbody_power(), 0.0);
+}
+
+#[test]
+fn blackbody_power_nan() {
+    assert!(Temperature::Kelvin(Float::NAN).blackbody_power().is_nan());
+    assert!(Temperature::Fahrenheit(Float::NAN)
+        .blackbody_power()
+        .is_nan());
+}
+
+#[test]
+fn peak_wavelength() {
+    // room temperature peaks in the far infrared, ~9.9 micrometers
+    let room = Temperature::Celsius(20.0);
+    assert_approx_eq!(room.peak_wavelength() / 9.885e-6, 1.0, 1e-3);
+    assert_approx_eq!(room.peak_wavelength(), WIEN_DISPLACEMENT / 293.15, 1e-12);
+
+    // and the sun peaks in visible light, ~502 nanometers
+    let sun = Temperature::Kelvin(5778.0);
+    assert_approx_eq!(sun.peak_wavelength() / 5.015e-7, 1.0, 1e-3);
+
+    // no peak at all at absolute zero
+    assert_eq!(Temperature::Kelvin(0.0).peak_wavelength(), Float::INFINITY);
+    assert_eq!(
+        Temperature::Fahrenheit(-500.0).peak_wavelength(),
+        Float::INFINITY
+    );
+}
+
+#[test]
+fn duty_cycle_endpoints() {
+    let ambient = Temperature::Celsius(22.0);
+    let max_rise = Temperature::Celsius(60.0);
+
+    // off is just ambient
+    assert_eq!(
+        Temperature::from_duty_cycle(0.0, ambient, max_rise),
+        ambient
+    );
+
+    // full power is ambient plus the whole rise
+    let full = Temperature::from_duty_cycle(1.0, ambient, max_rise);
+    assert!(matches!(full, Temperature::Celsius(_)));
+    assert_approx_eq!(full.into_inner(), 82.0);
+}
+
+#[test]
+fn duty_cycle_is_clamped() {
+    let ambient = Temperature::Kelvin(300.0);
+    let max_rise = Temperature::Kelvin(50.0);
+
+    assert_eq!(
+        Temperature::from_duty_cycle(-0.5, ambient, max_rise),
+        ambient
+    );
+    assert_eq!(
+        Temperature::from_duty_cycle(Float::NAN, ambient, max_rise),
+        ambient
+    );
+    assert_approx_eq!(
+        Temperature::from_duty_cycle(2.0, ambient, max_rise).into_inner(),
+        350.0
+    );
+}
+
+#[test]
+fn duty_cycle_rise_is_a_delta() {
+    // an 18 F rise is a 10 C rise (not -7.8 C!)
+    let ambient = Temperature::Celsius(20.0);
+    let max_rise = Temperature::Fahrenheit(18.0);
+
+    let full = Temperature::from_duty_cycle(1.0, ambient, max_rise);
+    assert_approx_eq!(full.into_inner(), 30.0, 1e-4);
+}
+
+#[test]
+fn drift_ppm_ten_degrees() {
+    let reference = Temperature::Celsius(25.0);
+    let tempco = 50.0; // a cheap resistor
+
+    assert_approx_eq!(
+        Temperature::Celsius(35.0).drift_ppm(reference, tempco),
+        500.0,
+        1e-3
+    );
+    assert_approx_eq!(
+        Temperature::Celsius(15.0).drift_ppm(reference, tempco),
+        -500.0,
+        1e-3
+    );
+
+    // the true difference is used, so units don't matter
+    assert_approx_eq!(
+        Temperature::Fahrenheit(95.0).drift_ppm(reference, tempco),
+        500.0,
+        1e-2
+    );
+    assert_approx_eq!(
+        Temperature::Kelvin(308.15).drift_ppm(reference, tempco),
+        500.0,
+        1e-2
+    );
+}
+
+#[test]
+fn drift_ppm_equal_is_zero() {
+    let reference = Temperature::Celsius(25.0);
+
+    assert_eq!(reference.drift_ppm(reference, 50.0), 0.0);
+    assert_eq!(reference.drift_ppm(reference, Float::INFINITY), 0.0);
+}
+
+/// Water's specific heat, in J/(kg·K).
+const WATER: Float = 4186.0;
+
+#[test]
+fn mix_equal_water() {
+    let cold = (Temperature::Celsius(0.0), 1.0, WATER);
+    let hot = (Temperature::Celsius(100.0), 1.0, WATER);
+
+    let result = Temperature::mix(cold, hot);
+    assert!(matches!(result, Temperature::Celsius(_)));
+    assert_approx_eq!(result.into_inner(), 50.0, 1e-3);
+}
+
+#[test]
+fn mix_returns_first_unit() {
+    let cold = (Temperature::Fahrenheit(32.0), 2.0, WATER);
+    let hot = (Temperature::Kelvin(373.15), 2.0, WATER);
+
+    let result = Temperature::mix(cold, hot);
+    assert!(matches!(result, Temperature::Fahrenheit(_)));
+    assert_approx_eq!(result.into_inner(), 122.0, 1e-3);
+}
+
+#[test]
+fn mix_weighted() {
+    // three times as much cold water
+    let cold = (Temperature::Celsius(0.0), 3.0, WATER);
+    let hot = (Temperature::Celsius(100.0), 1.0, WATER);
+
+    assert_approx_eq!(Temperature::mix(cold, hot).into_inner(), 25.0, 1e-3);
+}
+
+#[test]
+fn mix_zero_heat_capacity() {
+    let a = (Temperature::Celsius(10.0), 0.0, WATER);
+    let b = (Temperature::Celsius(90.0), 1.0, 0.0);
+    assert_eq!(Temperature::mix(a, b), Temperature::Celsius(10.0));
+
+    // one side with no capacity doesn't change anything
+    let b = (Temperature::Celsius(90.0), 1.0, WATER);
+    assert_approx_eq!(Temperature::mix(a, b).into_inner(), 90.0, 1e-3);
+}
+
+#[test]
+fn required_input_temp_inverts_mix() {
+    let tank = Temperature::Celsius(20.0);
+    let added = Temperature::Celsius(80.0);
+
+    let mixed = Temperature::mix((tank, 5.0, WATER), (added, 1.5, WATER));
+    let input = Temperature::required_input_temp(tank, 5.0, 1.5, mixed);
+
+    assert_eq!(input.unit(), simmer::Unit::Celsius);
+    assert_approx_eq!(input.into_inner(), 80.0, 1e-3);
+}
+
+#[test]
+fn required_input_temp_units_and_edges() {
+    // cooling down works, too, and the answer is in the tank's unit
+    let input = Temperature::required_input_temp(
+        Temperature::Fahrenheit(100.0),
+        1.0,
+        1.0,
+        Temperature::Fahrenheit(80.0),
+    );
+    assert_approx_eq!(input.into_inner(), 60.0, 1e-3);
+
+    // adding nothing gives back the target
+    let desired = Temperature::Celsius(50.0);
+    assert_eq!(
+        Temperature::required_input_temp(Temperature::Celsius(20.0), 10.0, 0.0, desired),
+        desired
+    );
+
+    // impossible targets aren't clamped
+    let impossible = Temperature::required_input_temp(
+        Temperature::Celsius(90.0),
+        10.0,
+        0.1,
+        Temperature::Celsius(10.0),
+    );
+    assert!(impossible.is_below_abs_zero());
+}
+
+#[test]
+fn cooling_curve_matches_newton() {
+    let start = Temperature::Celsius(90.0);
+    let ambient = Temperature::Celsius(20.0);
+    let (k, step): (Float, Float) = (0.05, 2.0);
+
+    let curve: Vec<Temperature> = Temperature::cooling_curve(start, ambient, k, step)
+        .take(20)
+        .collect();
+
+    for (n, temp) in curve.iter().enumerate() {
+        let t = n as Float * step;
+        let expected = 20.0 + 70.0 * (-k * t).exp();
+
+        assert_eq!(temp.unit(), simmer::Unit::Celsius);
+        assert_approx_eq!(temp.into_inner(), expected, 1e-3);
+    }
+}
+
+#[test]
+fn cooling_curve_heats_up_too() {
+    // a cold drink warming up, in Fahrenheit, with the ambient in Kelvin
+    let mut curve = Temperature::cooling_curve(
+        Temperature::Fahrenheit(40.0),
+        Temperature::Kelvin(300.0),
+        0.01,
+        30.0,
+    );
+
+    let first = curve.next().unwrap();
+    assert_approx_eq!(first.into_inner(), 40.0, 1e-3);
+
+    // it never gets colder, and never passes ambient
+    let ambient_f = Temperature::Kelvin(300.0).to_fahrenheit().into_inner();
+    let mut last = first.into_inner();
+
+    for temp in curve.take(100) {
+        assert!(temp.into_inner() >= last);
+        assert!(temp.into_inner() <= ambient_f + 1e-3);
+        last = temp.into_inner();
+    }
+
+    assert_approx_eq!(last, ambient_f, 1e-3);
+}
+
+#[test]
+fn cooling_curve_extremes() {
+    // no cooling at all
+    let flat: Vec<Temperature> = Temperature::cooling_curve(
+        Temperature::Kelvin(350.0),
+        Temperature::Kelvin(300.0),
+        0.0,
+        1.0,
+    )
+    .take(5)
+    .collect();
+    assert!(flat.iter().all(|t| (t.into_inner() - 350.0).abs() < 1e-3));
+
+    // instant cooling lands right on ambient after one step
+    let mut instant = Temperature::cooling_curve(
+        Temperature::Kelvin(350.0),
+        Temperature::Kelvin(300.0),
+        1e6,
+        1.0,
+    );
+    instant.next();
+    assert_approx_eq!(instant.next().unwrap().into_inner(), 300.0);
+}
+
+#[test]
+fn cooling_curve_decay_is_accurate() {
+    // the decay factor covers a wide range of exponents
+    for kt in [1e-6, 0.01, 0.5, 1.0, 2.5, 10.0, 30.0] {
+        let second =
+            Temperature::cooling_curve(Temperature::Kelvin(1.0), Temperature::Kelvin(0.0), kt, 1.0)
+                .nth(1)
+                .unwrap();
+
+        let expected: Float = (-kt as Float).exp();
+        assert!(
+            ((second.into_inner() - expected) / expected).abs() < 1e-5,
+            "e^-{kt}: {} vs {expected}",
+            second.into_inner()
+        );
+    }
+}
+
+#[test]
+fn cooling_curve_decay_near_float_limits() {
+    // with a negative `k`, the second point is exactly `e^-k`
+    let decay = |x: Float| {
+        Temperature::cooling_curve(Temperature::Kelvin(1.0), Temperature::Kelvin(0.0), -x, 1.0)
+            .nth(1)
+            .unwrap()
+            .into_inner()
+    };
+
+    // just below the overflow limit, the result is huge but finite
+    let x = Float::MAX.ln() - 0.01;
+    let huge = decay(x);
+    assert!(huge.is_finite());
+    assert!(((huge - x.exp()) / x.exp()).abs() < 1e-5, "{huge}");
+
+    // and just above the underflow limit, it's the smallest subnormal
+    let tiny = Float::from_bits(1);
+    assert_eq!(decay(tiny.ln() - 0.4), tiny);
+}
+
+#[test]
+fn absolute_humidity_reference() {
+    // saturated air at 25 C holds about 23.0 g/m³, so 60% RH is about 13.8
+    let ah = Temperature::absolute_humidity(Temperature::Celsius(25.0), 60.0);
+    assert_approx_eq!(ah, 13.82, 0.05);
+
+    // saturated air at freezing holds about 4.85 g/m³
+    let ah = Temperature::absolute_humidity(Temperature::Kelvin(273.15), 100.0);
+    assert_approx_eq!(ah, 4.85, 0.05);
+}
+
+#[test]
+fn absolute_humidity_clamps() {
+    let air = Temperature::Celsius(25.0);
+
+    assert_eq!(Temperature::absolute_humidity(air, 0.0), 0.0);
+    assert_eq!(Temperature::absolute_humidity(air, -20.0), 0.0);
+    assert_eq!(
+        Temperature::absolute_humidity(air, 150.0),
+        Temperature::absolute_humidity(air, 100.0)
+    );
+
+    assert!(Temperature::absolute_humidity(air, Float::NAN).is_nan());
+}
+
+#[test]
+fn wet_bulb_reference() {
+    // Stull (2011): 20 C at 50% RH gives a wet bulb of 13.7 C
+    let wet = Temperature::wet_bulb(Temperature::Celsius(20.0), 50.0);
+    assert!(matches!(wet, Temperature::Celsius(_)));
+    assert_approx_eq!(wet.into_inner(), 13.7, 0.01);
+
+    // same air, other units
+    let wet = Temperature::wet_bulb(Temperature::Fahrenheit(68.0), 50.0);
+    assert!(matches!(wet, Temperature::Fahrenheit(_)));
+    assert_approx_eq!(wet.to_celsius().into_inner(), 13.7, 0.01);
+
+    let wet = Temperature::wet_bulb(Temperature::Kelvin(293.15), 50.0);
+    assert!(matches!(wet, Temperature::Kelvin(_)));
+    assert_approx_eq!(wet.to_celsius().into_inner(), 13.7, 0.01);
+}
+
+#[test]
+fn wet_bulb_is_below_dry_bulb() {
+    // the fit wobbles a bit below freezing, so stick to warmer air here
+    for c in [0.0, 15.0, 30.0, 45.0] {
+        let air = Temperature::Celsius(c);
+
+        let mut last = Float::NEG_INFINITY;
+        for rh in [10.0, 30.0, 50.0, 70.0, 90.0] {
+            let wet = Temperature::wet_bulb(air, rh).into_inner();
+
+            // wetter air evaporates less, so it cools less
+            assert!(wet < c, "{c} C at {rh}% gave {wet}");
+            assert!(wet > last);
+            last = wet;
+        }
+    }
+}
+
+#[test]
+fn wet_bulb_clamps() {
+    let air = Temperature::Celsius(25.0);
+
+    assert_eq!(
+        Temperature::wet_bulb(air, 0.0),
+        Temperature::wet_bulb(air, 5.0)
+    );
+    assert_eq!(
+        Temperature::wet_bulb(air, 150.0),
+        Temperature::wet_bulb(air, 99.0)
+    );
+
+    assert!(Temperature::wet_bulb(air, Float::NAN).is_nan());
+    assert!(Temperature::wet_bulb(Temperature::Celsius(Float::NAN), 50.0).is_nan());
+}
+
+/// Steel's linear expansion coefficient, per Kelvin.
+const STEEL: Float = 12e-6;
+
+#[test]
+fn length_change_steel() {
+    // a 1 m steel rod heated by 100 K grows by 1.2 mm
+    let reference = Temperature::Celsius(20.0);
+    let hot = Temperature::Celsius(120.0);
+    assert_approx_eq!(hot.length_change(reference, 1.0, STEEL), 1.2e-3, 1e-6);
+
+    // the same rise in other units
+    let hot_k = Temperature::Kelvin(393.15);
+    assert_approx_eq!(hot_k.length_change(reference, 1.0, STEEL), 1.2e-3, 1e-6);
+
+    // cooling shrinks it
+    let cold = Temperature::Fahrenheit(-112.0); // -80 C
+    assert_approx_eq!(cold.length_change(reference, 1.0, STEEL), -1.2e-3, 1e-6);
+}
+
+#[test]
+fn length_change_equal_is_zero() {
+    let reference = Temperature::Celsius(0.0);
+
+    assert_eq!(reference.length_change(reference, 5.0, STEEL), 0.0);
+    assert_eq!(
+        Temperature::Kelvin(273.15).length_change(reference, 5.0, STEEL),
+        0.0
+    );
+}
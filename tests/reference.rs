@@ -0,0 +1,159 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{reference::REFERENCE_POINTS, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn body_temperature() {
+    let (name, value) = Temperature::Celsius(37.1).nearest_reference();
+
+    assert_eq!(name, "body temperature");
+    assert_eq!(value, Temperature::Celsius(37.0));
+}
+
+#[test]
+fn other_units() {
+    assert_eq!(
+        Temperature::Fahrenheit(33.0).nearest_reference().0,
+        "freezing point of water"
+    );
+    assert_eq!(
+        Temperature::Kelvin(295.0).nearest_reference().0,
+        "room temperature"
+    );
+    assert_eq!(
+        Temperature::Kelvin(3.0).nearest_reference().0,
+        "absolute zero"
+    );
+}
+
+#[test]
+fn out_of_range() {
+    assert_eq!(
+        Temperature::Celsius(5000.0).nearest_reference().0,
+        "boiling point of water"
+    );
+    assert_eq!(
+        Temperature::Kelvin(-10.0).nearest_reference().0,
+        "absolute zero"
+    );
+}
+
+#[test]
+fn every_reference_is_nearest_to_itself() {
+    for (name, value) in REFERENCE_POINTS {
+        assert_eq!(value.nearest_reference().0, name);
+    }
+}
+
+#[test]
+fn nan() {
+    let (name, _) = Temperature::Celsius(Float::NAN).nearest_reference();
+    assert_eq!(name, REFERENCE_POINTS[0].0);
+}
+
+#[test]
+fn relation_to_water_freezing_side() {
+    assert_eq!(
+        Temperature::Celsius(0.0).relation_to_water(),
+        (0.0, "at freezing")
+    );
+    assert_eq!(
+        Temperature::Fahrenheit(32.0).relation_to_water(),
+        (0.0, "at freezing")
+    );
+
+    let (delta, label) = Temperature::Celsius(5.0).relation_to_water();
+    assert_approx_eq!(delta, 5.0);
+    assert_eq!(label, "above freezing");
+
+    let (delta, label) = Temperature::Fahrenheit(14.0).relation_to_water();
+    assert_approx_eq!(delta, -10.0, 1e-4);
+    assert_eq!(label, "below freezing");
+}
+
+#[test]
+fn relation_to_water_boiling_side() {
+    assert_eq!(
+        Temperature::Celsius(100.0).relation_to_water(),
+        (0.0, "at boiling")
+    );
+
+    let (delta, label) = Temperature::Celsius(88.0).relation_to_water();
+    assert_approx_eq!(delta, -12.0);
+    assert_eq!(label, "below boiling");
+
+    let (delta, label) = Temperature::Kelvin(400.0).relation_to_water();
+    assert_approx_eq!(delta, 26.85, 1e-3);
+    assert_eq!(label, "above boiling");
+}
+
+#[test]
+fn relation_to_water_midpoint_and_nan() {
+    // ties go to freezing
+    let (delta, label) = Temperature::Celsius(50.0).relation_to_water();
+    assert_approx_eq!(delta, 50.0);
+    assert_eq!(label, "above freezing");
+
+    let (_, label) = Temperature::Celsius(50.5).relation_to_water();
+    assert_eq!(label, "below boiling");
+
+    let (delta, label) = Temperature::Celsius(Float::NAN).relation_to_water();
+    assert!(delta.is_nan());
+    assert_eq!(label, "unknown");
+}
+
+#[test]
+fn scale_crossover() {
+    assert!(Temperature::Celsius(-40.0).is_scale_crossover(0.0));
+    assert!(Temperature::Fahrenheit(-40.0).is_scale_crossover(1e-4));
+    assert!(Temperature::Kelvin(233.15).is_scale_crossover(1e-3));
+
+    // close, but only with a big enough epsilon
+    let nearby = Temperature::Celsius(-39.5);
+    assert!(!nearby.is_scale_crossover(0.1));
+    assert!(nearby.is_scale_crossover(0.5));
+
+    assert!(!Temperature::Celsius(0.0).is_scale_crossover(1.0));
+    assert!(!Temperature::Celsius(Float::NAN).is_scale_crossover(Float::INFINITY));
+}
+
+#[test]
+fn extreme_constants() {
+    let extremes = [
+        Temperature::PLANCK_TEMPERATURE,
+        Temperature::SUN_CORE,
+        Temperature::LIQUID_NITROGEN_BOILING,
+        Temperature::CMB,
+    ];
+
+    // all finite and physically valid, even on `f32`
+    for t in extremes {
+        assert!(t.get_inner().is_finite());
+        assert!(!t.is_below_abs_zero());
+    }
+
+    // from hottest to coldest
+    assert!(extremes
+        .windows(2)
+        .all(|pair| pair[0].into_inner() > pair[1].into_inner()));
+
+    assert_approx_eq!(
+        Temperature::PLANCK_TEMPERATURE.into_inner() / 1e32,
+        1.417,
+        1e-3
+    );
+    assert_approx_eq!(Temperature::CMB.into_inner(), 2.725);
+    assert_approx_eq!(
+        Temperature::LIQUID_NITROGEN_BOILING
+            .to_celsius()
+            .into_inner(),
+        -195.8,
+        1e-4
+    );
+}
@@ -0,0 +1,63 @@
+use simmer::{sample::Sample, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn accessors() {
+    let sample = Sample::new(Temperature::Celsius(21.0), 1234);
+
+    assert_eq!(sample.temp(), Temperature::Celsius(21.0));
+    assert_eq!(sample.millis(), 1234);
+    assert_eq!(Temperature::from(sample), Temperature::Celsius(21.0));
+}
+
+#[test]
+fn ordered_by_timestamp() {
+    let mut log = [
+        Sample::new(Temperature::Celsius(30.0), 300),
+        Sample::new(Temperature::Celsius(10.0), 100),
+        Sample::new(Temperature::Kelvin(0.0), 200),
+    ];
+    log.sort();
+
+    let times: Vec<u64> = log.iter().map(Sample::millis).collect();
+    assert_eq!(times, [100, 200, 300]);
+
+    // the temperature doesn't matter when the times differ
+    assert!(
+        Sample::new(Temperature::Celsius(1000.0), 1) < Sample::new(Temperature::Kelvin(0.0), 2)
+    );
+}
+
+#[test]
+fn same_timestamp_ties() {
+    let cold = Sample::new(Temperature::Celsius(0.0), 500);
+    let hot = Sample::new(Temperature::Celsius(100.0), 500);
+
+    assert!(cold < hot);
+    assert_ne!(cold, hot);
+
+    // physically equal readings at the same time are equal
+    assert_eq!(cold, Sample::new(Temperature::Kelvin(273.15), 500));
+
+    // NaN readings still have a place
+    let nan = Sample::new(Temperature::Celsius(Float::NAN), 500);
+    assert!(hot < nan);
+    assert_eq!(nan, nan);
+}
+
+#[test]
+fn age() {
+    let sample = Sample::new(Temperature::Celsius(21.0), 10_000);
+
+    assert_eq!(sample.age_from(10_000), 0);
+    assert_eq!(sample.age_from(12_345), 2_345);
+
+    // clocks can be weird
+    assert_eq!(sample.age_from(9_000), 0);
+}
@@ -0,0 +1,64 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{scale::LinearScale, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+/// Réaumur: water freezes at 0 and boils at 80.
+const REAUMUR: LinearScale = LinearScale::new(1.25, 0.0);
+
+#[test]
+fn reaumur_to_celsius() {
+    let cases: [(Float, Float); 4] = [(0.0, 0.0), (80.0, 100.0), (29.6, 37.0), (-218.52, -273.15)];
+
+    for (reaumur, celsius) in cases {
+        let converted = REAUMUR.to_celsius(reaumur);
+
+        assert!(matches!(converted, Temperature::Celsius(_)));
+        assert_approx_eq!(converted.into_inner(), celsius, 1e-3);
+    }
+}
+
+#[test]
+fn reaumur_from_other_units() {
+    assert_approx_eq!(
+        REAUMUR.from_celsius(Temperature::Celsius(100.0)),
+        80.0,
+        1e-4
+    );
+    assert_approx_eq!(
+        REAUMUR.from_celsius(Temperature::Fahrenheit(32.0)),
+        0.0,
+        1e-4
+    );
+    assert_approx_eq!(
+        REAUMUR.from_celsius(Temperature::Kelvin(0.0)),
+        -218.52,
+        1e-3
+    );
+}
+
+#[test]
+fn round_trip() {
+    for value in [-100.0, 0.0, 12.5, 80.0] {
+        assert_approx_eq!(REAUMUR.from_celsius(REAUMUR.to_celsius(value)), value, 1e-4);
+    }
+}
+
+#[test]
+fn abs_zero() {
+    assert_approx_eq!(REAUMUR.abs_zero, -218.52, 1e-3);
+    assert!(REAUMUR.is_below_abs_zero(-218.6));
+    assert!(!REAUMUR.is_below_abs_zero(-218.5));
+
+    // Delisle runs backwards: water boils at 0 and freezes at 150
+    let delisle = LinearScale::new(-2.0 / 3.0, 100.0);
+    assert_approx_eq!(delisle.to_celsius(150.0).into_inner(), 0.0, 1e-4);
+    assert_approx_eq!(delisle.abs_zero, 559.725, 1e-3);
+    assert!(delisle.is_below_abs_zero(560.0));
+    assert!(!delisle.is_below_abs_zero(0.0));
+}
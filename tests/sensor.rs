@@ -0,0 +1,93 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{
+    sensor::{FixedSensor, TemperatureSensor},
+    Temperature,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+/// A mock sensor that fails every few reads, like a flaky bus.
+struct FlakySensor {
+    temp: Temperature,
+    reads: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct BusError;
+
+impl TemperatureSensor for FlakySensor {
+    type Error = BusError;
+
+    fn read_temperature(&mut self) -> Result<Temperature, Self::Error> {
+        self.reads += 1;
+
+        if self.reads.is_multiple_of(3) {
+            return Err(BusError);
+        }
+
+        Ok(self.temp)
+    }
+}
+
+/// What a driver's user might write: works with any sensor.
+fn read_celsius<S: TemperatureSensor>(sensor: &mut S) -> Result<Temperature, S::Error> {
+    sensor.read_temperature().map(|t| t.to_celsius())
+}
+
+#[test]
+fn fixed_sensor() {
+    let mut sensor = FixedSensor::new(Temperature::Fahrenheit(98.6));
+    assert_eq!(sensor.reads(), 0);
+
+    for _ in 0..3 {
+        assert_eq!(sensor.read_temperature(), Ok(Temperature::Fahrenheit(98.6)));
+    }
+    assert_eq!(sensor.reads(), 3);
+
+    sensor.set(Temperature::Kelvin(300.0));
+    assert_eq!(sensor.read_temperature(), Ok(Temperature::Kelvin(300.0)));
+    assert_eq!(sensor.reads(), 4);
+}
+
+#[test]
+fn generic_over_sensors() {
+    let mut fixed = FixedSensor::new(Temperature::Fahrenheit(212.0));
+    assert_approx_eq!(read_celsius(&mut fixed).unwrap().into_inner(), 100.0);
+
+    // a `&mut` to a sensor is a sensor, too
+    let mut borrowed = &mut fixed;
+    assert!(read_celsius(&mut borrowed).is_ok());
+    assert_eq!(fixed.reads(), 2);
+}
+
+#[test]
+fn errors_come_through() {
+    let mut sensor = FlakySensor {
+        temp: Temperature::Celsius(21.0),
+        reads: 0,
+    };
+
+    assert_eq!(read_celsius(&mut sensor), Ok(Temperature::Celsius(21.0)));
+    assert_eq!(read_celsius(&mut sensor), Ok(Temperature::Celsius(21.0)));
+    assert_eq!(read_celsius(&mut sensor), Err(BusError));
+}
+
+#[test]
+fn trait_objects_work() {
+    let mut sensors: [&mut dyn TemperatureSensor<Error = core::convert::Infallible>; 2] = [
+        &mut FixedSensor::new(Temperature::Celsius(1.0)),
+        &mut FixedSensor::new(Temperature::Celsius(2.0)),
+    ];
+
+    let total = sensors
+        .iter_mut()
+        .map(|s| s.read_temperature().unwrap().into_inner())
+        .sum::<Float>();
+
+    assert_approx_eq!(total, 3.0);
+}
@@ -0,0 +1,51 @@
+use simmer::{
+    strict::{StrictTemperature, StrictTemperatureError},
+    Temperature, Unit,
+};
+
+#[test]
+fn same_unit_arithmetic() {
+    let a = StrictTemperature::new(Temperature::Celsius(20.0));
+    let b = StrictTemperature::new(Temperature::Celsius(5.0));
+
+    assert_eq!((a + b).get(), Temperature::Celsius(25.0));
+    assert_eq!((a - b).get(), Temperature::Celsius(15.0));
+    assert_eq!(a.try_add(b).unwrap().get(), Temperature::Celsius(25.0));
+    assert_eq!(a.try_sub(b).unwrap().get(), Temperature::Celsius(15.0));
+}
+
+#[test]
+fn mixed_units_error() {
+    let c = StrictTemperature::from(Temperature::Celsius(20.0));
+    let f = StrictTemperature::from(Temperature::Fahrenheit(68.0));
+
+    let err = StrictTemperatureError::UnitMismatch {
+        left: Unit::Celsius,
+        right: Unit::Fahrenheit,
+    };
+
+    assert_eq!(c.try_add(f), Err(err));
+    assert_eq!(c.try_sub(f), Err(err));
+    assert_eq!(
+        format!("{err}"),
+        "Can't combine a Celsius temperature with a Fahrenheit one."
+    );
+}
+
+#[test]
+#[should_panic(expected = "Can't combine a Kelvin temperature with a Celsius one.")]
+fn mixed_units_add_panics() {
+    let k = StrictTemperature::new(Temperature::Kelvin(300.0));
+    let c = StrictTemperature::new(Temperature::Celsius(1.0));
+
+    let _ = k + c;
+}
+
+#[test]
+#[should_panic]
+fn mixed_units_sub_panics() {
+    let k = StrictTemperature::new(Temperature::Kelvin(300.0));
+    let f = StrictTemperature::new(Temperature::Fahrenheit(1.0));
+
+    let _ = k - f;
+}
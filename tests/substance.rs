@@ -0,0 +1,80 @@
+use simmer::{substance::Substance, Temperature};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+const SUBSTANCES: [Substance; 5] = [
+    Substance::Water,
+    Substance::Nitrogen,
+    Substance::Mercury,
+    Substance::Ethanol,
+    Substance::CO2,
+];
+
+#[test]
+fn nitrogen_is_gas_at_room_temperature() {
+    let room = Temperature::Celsius(20.0);
+
+    assert!(room.is_gas(Substance::Nitrogen));
+    assert!(!room.is_liquid(Substance::Nitrogen));
+    assert!(!room.is_solid(Substance::Nitrogen));
+}
+
+#[test]
+fn liquid_nitrogen() {
+    // liquid nitrogen sits at about 77 K
+    let ln2 = Temperature::Kelvin(70.0);
+    assert!(ln2.is_liquid(Substance::Nitrogen));
+}
+
+#[test]
+fn water_states() {
+    assert!(Temperature::Fahrenheit(20.0).is_solid(Substance::Water));
+    assert!(Temperature::Fahrenheit(32.0).is_liquid(Substance::Water));
+    assert!(Temperature::Fahrenheit(98.6).is_liquid(Substance::Water));
+    assert!(Temperature::Kelvin(373.15).is_gas(Substance::Water));
+}
+
+#[test]
+fn co2_sublimates() {
+    let dry_ice = Temperature::Celsius(-80.0);
+    let air = Temperature::Celsius(-70.0);
+
+    assert!(dry_ice.is_solid(Substance::CO2));
+    assert!(air.is_gas(Substance::CO2));
+
+    for c in [-100.0, -78.46, -78.0, 0.0] {
+        assert!(!Temperature::Celsius(c).is_liquid(Substance::CO2));
+    }
+}
+
+#[test]
+fn exactly_one_state() {
+    for substance in SUBSTANCES {
+        for c in [-250.0, -200.0, -100.0, -50.0, 0.0, 50.0, 100.0, 400.0] {
+            let temp = Temperature::Celsius(c);
+            let states = [
+                temp.is_solid(substance),
+                temp.is_liquid(substance),
+                temp.is_gas(substance),
+            ];
+
+            assert_eq!(states.iter().filter(|s| **s).count(), 1);
+        }
+    }
+}
+
+#[test]
+fn nan_has_no_state() {
+    let nan = Temperature::Celsius(Float::NAN);
+
+    for substance in SUBSTANCES {
+        assert!(!nan.is_solid(substance));
+        assert!(!nan.is_liquid(substance));
+        assert!(!nan.is_gas(substance));
+    }
+}
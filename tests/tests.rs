@@ -1,5 +1,5 @@
 use assert_approx_eq::assert_approx_eq;
-use simmer::Temperature;
+use simmer::{Temperature, TemperatureError, Unit};
 
 // just like in the lib itself...
 #[cfg(not(feature = "f32"))]
@@ -97,3 +97,984 @@ fn zeroes() {
 
     test_all!(zero_f, zero_c, zero_k);
 }
+
+#[test]
+fn percent_difference() {
+    let a = Temperature::Kelvin(300.0);
+    let b = Temperature::Kelvin(303.0);
+
+    // |300 - 303| / 301.5 * 100
+    assert_approx_eq!(a.percent_difference(b), 0.995_024_9, 1e-4);
+    assert_approx_eq!(b.percent_difference(a), a.percent_difference(b));
+
+    // mixed units are compared in kelvin
+    let c = Temperature::Celsius(26.85);
+    assert_approx_eq!(a.percent_difference(c), 0.0, 1e-4);
+
+    // both at absolute zero
+    assert_approx_eq!(
+        Temperature::Kelvin(0.0).percent_difference(Temperature::Celsius(-273.15)),
+        0.0,
+        1e-4
+    );
+}
+
+#[test]
+fn from_value_and_unit() {
+    let f = Temperature::from((98.6, Unit::Fahrenheit));
+    let c = Temperature::from((25.0, Unit::Celsius));
+    let k = Temperature::from((273.15, Unit::Kelvin));
+
+    assert_eq!(f, Temperature::Fahrenheit(98.6));
+    assert_eq!(c, Temperature::Celsius(25.0));
+    assert_eq!(k, Temperature::Kelvin(273.15));
+
+    assert_eq!(f.unit(), Unit::Fahrenheit);
+    assert_eq!(c.unit(), Unit::Celsius);
+    assert_eq!(k.unit(), Unit::Kelvin);
+}
+
+#[test]
+fn saturating_conversions() {
+    let huge = Temperature::Kelvin(Float::MAX * 0.9);
+
+    assert!(huge.to_fahrenheit().into_inner().is_infinite());
+    assert_eq!(huge.to_fahrenheit_saturating().into_inner(), Float::MAX);
+
+    let tiny = Temperature::Celsius(Float::MIN * 0.9);
+    assert_eq!(tiny.to_fahrenheit_saturating().into_inner(), Float::MIN);
+
+    // infinite inputs are clamped, too
+    assert_eq!(
+        Temperature::Fahrenheit(Float::INFINITY)
+            .to_kelvin_saturating()
+            .into_inner(),
+        Float::MAX
+    );
+    assert_eq!(
+        Temperature::Kelvin(Float::NEG_INFINITY)
+            .to_celsius_saturating()
+            .into_inner(),
+        Float::MIN
+    );
+
+    // normal values are untouched
+    assert_eq!(
+        Temperature::Celsius(100.0).to_fahrenheit_saturating(),
+        Temperature::Celsius(100.0).to_fahrenheit()
+    );
+    assert!(Temperature::Celsius(Float::NAN)
+        .to_kelvin_saturating()
+        .is_nan());
+}
+
+#[test]
+#[cfg(feature = "f32")]
+fn saturating_near_f32_max() {
+    let near_max = Temperature::Kelvin(3.0e38);
+
+    assert!(near_max.to_fahrenheit().into_inner().is_infinite());
+    assert!(near_max.to_fahrenheit_saturating().into_inner().is_finite());
+}
+
+#[test]
+fn try_into_inner() {
+    assert_eq!(Temperature::Kelvin(300.0).try_into_inner(), Ok(300.0));
+    assert_eq!(Temperature::Fahrenheit(-40.0).try_into_inner(), Ok(-40.0));
+
+    assert_eq!(
+        Temperature::Celsius(Float::NAN).try_into_inner(),
+        Err(TemperatureError::IsNan)
+    );
+    assert_eq!(
+        Temperature::Celsius(Float::INFINITY).try_into_inner(),
+        Err(TemperatureError::IsInfinite(Float::INFINITY))
+    );
+    assert_eq!(
+        Temperature::Kelvin(Float::NEG_INFINITY).try_into_inner(),
+        Err(TemperatureError::IsInfinite(Float::NEG_INFINITY))
+    );
+}
+
+#[test]
+fn named_constructors() {
+    assert_eq!(Temperature::fahrenheit(98.6), Temperature::Fahrenheit(98.6));
+    assert_eq!(Temperature::celsius(25.0), Temperature::Celsius(25.0));
+    assert_eq!(Temperature::kelvin(273.15), Temperature::Kelvin(273.15));
+
+    // they're usable in const contexts, too
+    const ICE: Temperature = Temperature::celsius(0.0);
+    assert_eq!(ICE, Temperature::Celsius(0.0));
+}
+
+#[test]
+fn unit_display() {
+    assert_eq!(format!("{}", Unit::Fahrenheit), "Fahrenheit");
+    assert_eq!(format!("{}", Unit::Celsius), "Celsius");
+    assert_eq!(format!("{}", Unit::Kelvin), "Kelvin");
+}
+
+#[test]
+fn with_unit_relabels() {
+    assert_eq!(
+        Temperature::Celsius(100.0).with_unit(Unit::Fahrenheit),
+        Temperature::Fahrenheit(100.0)
+    );
+    assert_eq!(
+        Temperature::Fahrenheit(-3.5).with_unit(Unit::Kelvin),
+        Temperature::Kelvin(-3.5)
+    );
+    assert_eq!(
+        Temperature::Kelvin(1.0).with_unit(Unit::Kelvin),
+        Temperature::Kelvin(1.0)
+    );
+}
+
+#[test]
+fn to_unit_converts() {
+    let boiling = Temperature::Celsius(100.0);
+
+    assert_eq!(boiling.to_unit(Unit::Fahrenheit), boiling.to_fahrenheit());
+    assert_eq!(boiling.to_unit(Unit::Celsius), boiling);
+    assert_eq!(boiling.to_unit(Unit::Kelvin), boiling.to_kelvin());
+}
+
+#[test]
+fn physical_min_max() {
+    let ice_c = Temperature::Celsius(0.0);
+    let warm_f = Temperature::Fahrenheit(40.0); // ~4.4 C
+    let hot_k = Temperature::Kelvin(400.0);
+
+    // derived `PartialOrd` only looks at the variant here, so it'd be wrong!
+    assert_eq!(simmer::min(ice_c, warm_f), ice_c);
+    assert_eq!(simmer::min(warm_f, ice_c), ice_c);
+    assert_eq!(simmer::max(ice_c, warm_f), warm_f);
+    assert_eq!(simmer::max(hot_k, warm_f), hot_k);
+    assert_eq!(simmer::min(hot_k, warm_f), warm_f);
+
+    // ties give back the first one
+    let ice_f = Temperature::Fahrenheit(32.0);
+    assert_eq!(simmer::min(ice_c, ice_f), ice_c);
+    assert_eq!(simmer::max(ice_f, ice_c), ice_f);
+
+    // NaN is ignored
+    let nan = Temperature::Kelvin(Float::NAN);
+    assert_eq!(simmer::min(nan, ice_c), ice_c);
+    assert_eq!(simmer::max(ice_c, nan), ice_c);
+    assert!(simmer::max(nan, nan).is_nan());
+}
+
+#[test]
+fn millikelvin_round_trip() {
+    for temp in [
+        Temperature::Kelvin(0.0),
+        Temperature::Celsius(21.0),
+        Temperature::Fahrenheit(98.6),
+        Temperature::Kelvin(1234.5678),
+        Temperature::Celsius(-300.0),
+    ] {
+        let mk = temp.to_millikelvin();
+        let back = Temperature::from_millikelvin(mk);
+
+        assert!(matches!(back, Temperature::Kelvin(_)));
+        assert_approx_eq!(back.into_inner(), temp.to_kelvin().into_inner(), 5e-4);
+    }
+}
+
+#[test]
+fn millikelvin_rounding() {
+    assert_eq!(Temperature::Kelvin(1.0004).to_millikelvin(), 1000);
+    assert_eq!(Temperature::Kelvin(1.0006).to_millikelvin(), 1001);
+    assert_eq!(Temperature::Kelvin(-1.0006).to_millikelvin(), -1001);
+
+    // out of range values saturate
+    assert_eq!(Temperature::Kelvin(1.0e10).to_millikelvin(), i32::MAX);
+    assert_eq!(Temperature::Kelvin(-1.0e10).to_millikelvin(), i32::MIN);
+    assert_eq!(Temperature::Kelvin(Float::NAN).to_millikelvin(), 0);
+}
+
+#[test]
+fn is_within_cross_unit() {
+    let lower = Temperature::Fahrenheit(32.0);
+    let upper = Temperature::Fahrenheit(212.0);
+
+    assert!(Temperature::Celsius(50.0).is_within(lower, upper));
+    assert!(Temperature::Kelvin(300.0).is_within(lower, upper));
+    assert!(!Temperature::Celsius(-1.0).is_within(lower, upper));
+    assert!(!Temperature::Celsius(101.0).is_within(lower, upper));
+
+    // bounds are inclusive
+    assert!(Temperature::Celsius(0.0).is_within(lower, upper));
+    assert!(Temperature::Celsius(100.0).is_within(lower, upper));
+    assert!(Temperature::Fahrenheit(212.0).is_within(lower, upper));
+
+    // backwards bounds contain nothing
+    assert!(!Temperature::Celsius(50.0).is_within(upper, lower));
+
+    assert!(!Temperature::Celsius(Float::NAN).is_within(lower, upper));
+}
+
+#[test]
+fn round_trip_error() {
+    #[cfg(not(feature = "f32"))]
+    let tolerance: Float = 1e-9;
+
+    #[cfg(feature = "f32")]
+    let tolerance: Float = 1e-4;
+
+    let units = [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin];
+
+    // nice, integer-friendly values barely move
+    for temp in [
+        Temperature::Celsius(100.0),
+        Temperature::Fahrenheit(32.0),
+        Temperature::Kelvin(300.0),
+    ] {
+        for via in units {
+            assert!(temp.round_trip_error(via) < tolerance);
+        }
+
+        assert_eq!(temp.round_trip_error(temp.unit()), 0.0);
+    }
+
+    // tiny values get swamped by the kelvin offset
+    let tiny = Temperature::Celsius(1e-10);
+    assert!(tiny.round_trip_error(Unit::Kelvin) > 0.0);
+}
+
+#[test]
+fn compare_with_float() {
+    // no conversion happens at all
+    for temp in [
+        Temperature::Fahrenheit(100.0),
+        Temperature::Celsius(100.0),
+        Temperature::Kelvin(100.0),
+    ] {
+        assert!(temp == 100.0);
+        assert!(temp != 100.5);
+        assert!(temp > 99.9);
+        assert!(temp >= 100.0);
+        assert!(temp < 100.1);
+        assert!(temp <= 100.0);
+    }
+
+    assert!(Temperature::Celsius(Float::NAN) != 0.0);
+    assert_eq!(Temperature::Celsius(Float::NAN).partial_cmp(&0.0), None);
+}
+
+#[test]
+fn sanitized() {
+    // NaN becomes absolute zero
+    assert_eq!(
+        Temperature::Celsius(Float::NAN).sanitized(),
+        Temperature::Kelvin(0.0)
+    );
+
+    // below absolute zero is clamped in the same unit
+    assert_eq!(
+        Temperature::Fahrenheit(-500.0).sanitized(),
+        Temperature::Fahrenheit(-459.67)
+    );
+    assert_eq!(
+        Temperature::Celsius(-273.16).sanitized(),
+        Temperature::Celsius(-273.15)
+    );
+    assert_eq!(
+        Temperature::Kelvin(Float::NEG_INFINITY).sanitized(),
+        Temperature::Kelvin(0.0)
+    );
+
+    // infinity saturates
+    assert_eq!(
+        Temperature::Celsius(Float::INFINITY).sanitized(),
+        Temperature::Celsius(Float::MAX)
+    );
+
+    // valid temperatures are untouched
+    for temp in [
+        Temperature::Fahrenheit(-459.67),
+        Temperature::Celsius(21.0),
+        Temperature::Kelvin(0.0),
+    ] {
+        assert_eq!(temp.sanitized(), temp);
+        assert!(!temp.sanitized().is_below_abs_zero());
+    }
+}
+
+#[test]
+fn normalize() {
+    let normalized = Temperature::Fahrenheit(32.0).normalize();
+
+    assert_eq!(normalized.unit(), Unit::Kelvin);
+    assert_approx_eq!(normalized.into_inner(), 273.15, 1e-4);
+
+    // kelvin is left alone
+    let already = Temperature::Kelvin(300.0);
+    assert_eq!(already.normalize(), already);
+}
+
+#[test]
+fn is_normalized() {
+    assert!(Temperature::Kelvin(0.0).is_normalized());
+    assert!(!Temperature::Celsius(0.0).is_normalized());
+    assert!(!Temperature::Fahrenheit(0.0).is_normalized());
+
+    assert!(Temperature::Celsius(21.0).normalize().is_normalized());
+}
+
+#[test]
+fn checked_arithmetic() {
+    let a = Temperature::Celsius(20.0);
+    let b = Temperature::Celsius(5.0);
+
+    assert_eq!(a.checked_add(b), Some(a + b));
+    assert_eq!(a.checked_sub(b), Some(a - b));
+    assert_eq!(a.checked_mul(2.0), Some(a * 2.0));
+    assert_eq!(a.checked_div(2.0), Some(a / 2.0));
+}
+
+#[test]
+fn checked_add_near_max_is_none() {
+    // on `f32`, this is right where conversions and additions blow up
+    let near_max = Temperature::Kelvin(Float::MAX * 0.75);
+
+    assert_eq!(near_max.checked_add(near_max), None);
+    assert_eq!(
+        Temperature::Kelvin(-Float::MAX * 0.75).checked_sub(near_max),
+        None
+    );
+    assert_eq!(near_max.checked_mul(2.0), None);
+}
+
+#[test]
+fn checked_div_by_zero_is_none() {
+    assert_eq!(Temperature::Kelvin(300.0).checked_div(0.0), None);
+    assert_eq!(Temperature::Kelvin(300.0).checked_div(-0.0), None);
+    assert_eq!(Temperature::Kelvin(Float::MAX).checked_div(0.5), None);
+}
+
+#[test]
+fn checked_arithmetic_rejects_nan() {
+    let nan = Temperature::Kelvin(Float::NAN);
+
+    assert_eq!(nan.checked_add(Temperature::Kelvin(1.0)), None);
+    assert_eq!(Temperature::Kelvin(1.0).checked_mul(Float::NAN), None);
+}
+
+#[test]
+fn absolute_zero_in() {
+    assert_eq!(
+        Temperature::absolute_zero_in(Unit::Fahrenheit),
+        Temperature::Fahrenheit(-459.67)
+    );
+    assert_eq!(
+        Temperature::absolute_zero_in(Unit::Celsius),
+        Temperature::Celsius(-273.15)
+    );
+    assert_eq!(
+        Temperature::absolute_zero_in(Unit::Kelvin),
+        Temperature::Kelvin(0.0)
+    );
+}
+
+#[test]
+fn absolute_zero() {
+    for temp in [
+        Temperature::Fahrenheit(70.0),
+        Temperature::Celsius(21.0),
+        Temperature::Kelvin(294.15),
+    ] {
+        let zero = temp.absolute_zero();
+
+        assert_eq!(zero.unit(), temp.unit());
+        assert!(!zero.is_below_abs_zero());
+        assert_approx_eq!(zero.to_kelvin().into_inner(), 0.0, 1e-4);
+    }
+}
+
+#[test]
+fn convert_lossy_check_clean() {
+    let (converted, lossy) = Temperature::Celsius(100.0).convert_lossy_check(Unit::Kelvin, 1e-3);
+
+    assert_approx_eq!(converted.into_inner(), 373.15, 1e-4);
+    assert_eq!(converted.unit(), Unit::Kelvin);
+    assert!(!lossy);
+
+    // same unit is always clean
+    let (_, lossy) = Temperature::Kelvin(1e-7).convert_lossy_check(Unit::Kelvin, 0.0);
+    assert!(!lossy);
+}
+
+#[test]
+fn convert_lossy_check_lossy() {
+    // a tiny value gets swamped by the 273.15 offset
+    let tiny = Temperature::Kelvin(1e-7);
+    let (converted, lossy) = tiny.convert_lossy_check(Unit::Celsius, 0.0);
+
+    assert_eq!(converted, tiny.to_celsius());
+    assert!(lossy);
+
+    // non-finite values are always suspect
+    let (_, lossy) = Temperature::Kelvin(Float::NAN).convert_lossy_check(Unit::Celsius, 1.0);
+    assert!(lossy);
+}
+
+#[test]
+fn new_finite() {
+    assert_eq!(
+        Temperature::new_finite(Unit::Fahrenheit, 98.6),
+        Some(Temperature::Fahrenheit(98.6))
+    );
+
+    // no abs. zero check here!
+    assert_eq!(
+        Temperature::new_finite(Unit::Kelvin, -5.0),
+        Some(Temperature::Kelvin(-5.0))
+    );
+}
+
+#[test]
+fn new_finite_rejects_nan() {
+    assert_eq!(Temperature::new_finite(Unit::Celsius, Float::NAN), None);
+}
+
+#[test]
+fn new_finite_rejects_infinity() {
+    assert_eq!(Temperature::new_finite(Unit::Kelvin, Float::INFINITY), None);
+    assert_eq!(
+        Temperature::new_finite(Unit::Fahrenheit, Float::NEG_INFINITY),
+        None
+    );
+}
+
+#[test]
+fn total_cmp_kelvin_sorts_nan() {
+    let mut readings = [
+        Temperature::Kelvin(Float::NAN),
+        Temperature::Celsius(100.0),
+        Temperature::Fahrenheit(32.0),
+        Temperature::Kelvin(0.0),
+        Temperature::Celsius(20.0),
+    ];
+    readings.sort_by(Temperature::total_cmp_kelvin);
+
+    assert_eq!(
+        readings[..4],
+        [
+            Temperature::Kelvin(0.0),
+            Temperature::Fahrenheit(32.0),
+            Temperature::Celsius(20.0),
+            Temperature::Celsius(100.0),
+        ]
+    );
+    assert!(readings[4].is_nan());
+}
+
+#[test]
+fn total_cmp_kelvin_is_physical() {
+    use std::cmp::Ordering;
+
+    let ice_c = Temperature::Celsius(0.0);
+    let ice_k = Temperature::Kelvin(273.15);
+
+    assert_eq!(ice_c.total_cmp_kelvin(&ice_k), Ordering::Equal);
+    assert_eq!(
+        Temperature::Fahrenheit(100.0).total_cmp_kelvin(&Temperature::Celsius(100.0)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn register_round_trip() {
+    for temp in [
+        Temperature::Fahrenheit(98.6),
+        Temperature::Celsius(-40.0),
+        Temperature::Celsius(0.0),
+        Temperature::Kelvin(273.15),
+        Temperature::Kelvin(-12.34),
+        Temperature::Fahrenheit(123_456.78),
+    ] {
+        let back = Temperature::from_register(temp.to_register()).unwrap();
+
+        assert_eq!(back.unit(), temp.unit());
+        assert_approx_eq!(back.into_inner(), temp.into_inner(), 0.01);
+    }
+}
+
+#[test]
+fn register_layout() {
+    assert_eq!(Temperature::Fahrenheit(1.0).to_register(), 100);
+    assert_eq!(Temperature::Celsius(1.0).to_register(), (1 << 30) | 100);
+    assert_eq!(Temperature::Kelvin(1.0).to_register(), (2 << 30) | 100);
+
+    // negative values are 30-bit two's complement
+    assert_eq!(Temperature::Fahrenheit(-0.01).to_register(), 0x3FFF_FFFF);
+
+    // rounding to the nearest hundredth
+    assert_eq!(Temperature::Fahrenheit(0.004).to_register(), 0);
+    assert_eq!(Temperature::Fahrenheit(0.006).to_register(), 1);
+}
+
+#[test]
+fn register_saturates() {
+    let max = Temperature::from_register(Temperature::Kelvin(1e30).to_register()).unwrap();
+    let min = Temperature::from_register(Temperature::Kelvin(-1e30).to_register()).unwrap();
+
+    assert_approx_eq!(max.into_inner(), 5_368_709.11, 0.01);
+    assert_approx_eq!(min.into_inner(), -5_368_709.12, 0.01);
+
+    // NaN is zero
+    assert_eq!(
+        Temperature::Celsius(Float::NAN).to_register(),
+        Temperature::Celsius(0.0).to_register()
+    );
+}
+
+#[test]
+fn register_rejects_reserved_tag() {
+    assert_eq!(Temperature::from_register(0xC000_0000), None);
+    assert_eq!(Temperature::from_register(0xFFFF_FFFF), None);
+}
+
+/// Steps `x` forward by `n` representable floats.
+fn next_up(x: Float, n: u32) -> Float {
+    let mut x = x;
+    for _ in 0..n {
+        x = Float::from_bits(x.to_bits() + 1);
+    }
+    x
+}
+
+#[test]
+fn is_close_to_ulps() {
+    for k in [1e-3, 1.0, 300.0, 5778.0, 1e12] {
+        let a = Temperature::Kelvin(k);
+
+        assert!(a.is_close_to(a, 0));
+        assert!(a.is_close_to(Temperature::Kelvin(next_up(k, 1)), 1));
+        assert!(a.is_close_to(Temperature::Kelvin(next_up(k, 4)), 4));
+        assert!(!a.is_close_to(Temperature::Kelvin(next_up(k, 5)), 4));
+
+        // it's symmetric
+        assert!(Temperature::Kelvin(next_up(k, 3)).is_close_to(a, 3));
+    }
+}
+
+#[test]
+fn is_close_to_scales_with_magnitude() {
+    // an absolute difference of 1e-3 K is huge for a tiny value...
+    let tiny = Temperature::Kelvin(1e-3);
+    assert!(!tiny.is_close_to(Temperature::Kelvin(2e-3), 1000));
+
+    // ...but less than an ULP away for a huge one
+    let huge = Temperature::Kelvin(1e15);
+    assert!(huge.is_close_to(Temperature::Kelvin(1e15 + 1e-3), 1));
+}
+
+#[test]
+fn is_close_to_across_units() {
+    let ice_c = Temperature::Celsius(0.0);
+    let ice_k = Temperature::Kelvin(273.15);
+
+    assert!(ice_c.is_close_to(ice_k, 0));
+    assert!(!ice_c.is_close_to(Temperature::Fahrenheit(33.0), 1000));
+}
+
+#[test]
+fn is_close_to_edge_cases() {
+    let nan = Temperature::Kelvin(Float::NAN);
+    assert!(!nan.is_close_to(nan, u32::MAX));
+    assert!(!Temperature::Kelvin(1.0).is_close_to(nan, u32::MAX));
+
+    // signed zeros are equal
+    assert!(Temperature::Kelvin(0.0).is_close_to(Temperature::Kelvin(-0.0), 0));
+
+    // tiny values across zero
+    let up = Temperature::Kelvin(next_up(0.0, 1));
+    let down = Temperature::Kelvin(-next_up(0.0, 1));
+    assert!(up.is_close_to(down, 2));
+    assert!(!up.is_close_to(down, 1));
+
+    // infinity is right after the max
+    assert!(Temperature::Kelvin(Float::MAX).is_close_to(Temperature::Kelvin(Float::INFINITY), 1));
+}
+
+#[test]
+fn convert_tracked_same_unit() {
+    for temp in [
+        Temperature::Fahrenheit(70.0),
+        Temperature::Celsius(21.0),
+        Temperature::Kelvin(294.15),
+    ] {
+        assert_eq!(temp.convert_tracked(temp.unit()), (temp, false));
+    }
+}
+
+#[test]
+fn convert_tracked_different_unit() {
+    let (converted, did_work) = Temperature::Celsius(100.0).convert_tracked(Unit::Fahrenheit);
+
+    assert!(did_work);
+    assert_eq!(converted.unit(), Unit::Fahrenheit);
+    assert_approx_eq!(converted.into_inner(), 212.0);
+}
+
+#[test]
+fn canonicalize_zero() {
+    for unit in [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin] {
+        let negative = Temperature::from((-0.0, unit));
+        let canonical = negative.canonicalize_zero();
+
+        assert_eq!(canonical.unit(), unit);
+        assert!(canonical.into_inner().is_sign_positive());
+
+        // positive zero and everything else are untouched
+        let positive = Temperature::from((0.0, unit));
+        assert!(positive.canonicalize_zero().into_inner().is_sign_positive());
+        assert_eq!(
+            Temperature::from((-1.5, unit)).canonicalize_zero(),
+            Temperature::from((-1.5, unit))
+        );
+    }
+
+    assert!(Temperature::Celsius(Float::NAN)
+        .canonicalize_zero()
+        .is_nan());
+}
+
+#[test]
+fn negative_zero_compares_equal() {
+    use std::cmp::Ordering;
+
+    for unit in [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin] {
+        let negative = Temperature::from((-0.0, unit));
+        let positive = Temperature::from((0.0, unit));
+
+        assert_eq!(negative, positive);
+        assert_eq!(negative.total_cmp_kelvin(&positive), Ordering::Equal);
+        assert!(negative.is_close_to(positive, 0));
+        assert_approx_eq!(
+            negative.to_kelvin().into_inner(),
+            positive.to_kelvin().into_inner()
+        );
+        assert_eq!(negative.is_below_abs_zero(), positive.is_below_abs_zero());
+    }
+
+    // the kelvin one is right on absolute zero - not below it
+    assert!(!Temperature::Kelvin(-0.0).is_below_abs_zero());
+}
+
+#[test]
+fn to_all_matches_individual_conversions() {
+    for temp in [
+        Temperature::Fahrenheit(98.6),
+        Temperature::Celsius(-40.0),
+        Temperature::Kelvin(0.0),
+        Temperature::Celsius(Float::NAN),
+    ] {
+        let (c, f, k) = temp.to_all();
+
+        assert_eq!(c.unit(), Unit::Celsius);
+        assert_eq!(f.unit(), Unit::Fahrenheit);
+        assert_eq!(k.unit(), Unit::Kelvin);
+
+        // bit-for-bit, so NaN counts too
+        assert_eq!(
+            c.into_inner().to_bits(),
+            temp.to_celsius().into_inner().to_bits()
+        );
+        assert_eq!(
+            f.into_inner().to_bits(),
+            temp.to_fahrenheit().into_inner().to_bits()
+        );
+        assert_eq!(
+            k.into_inner().to_bits(),
+            temp.to_kelvin().into_inner().to_bits()
+        );
+    }
+}
+
+#[test]
+fn self_test_passes() {
+    assert_eq!(simmer::self_test(), Ok(()));
+}
+
+#[test]
+fn from_f64_saturating() {
+    let t = Temperature::from_f64_saturating(Unit::Celsius, 21.5);
+    assert_eq!(t, Temperature::Celsius(21.5));
+
+    let huge = Temperature::from_f64_saturating(Unit::Kelvin, 1e300);
+    let tiny = Temperature::from_f64_saturating(Unit::Fahrenheit, -1e300);
+    assert_eq!(huge.unit(), Unit::Kelvin);
+
+    #[cfg(feature = "f32")]
+    {
+        // way past `f32::MAX`, but it doesn't become infinity
+        assert_eq!(huge.into_inner(), f32::MAX);
+        assert_eq!(tiny.into_inner(), f32::MIN);
+
+        // a plain cast would
+        assert!((1e300_f64 as f32).is_infinite());
+    }
+
+    #[cfg(not(feature = "f32"))]
+    {
+        assert_eq!(huge.into_inner(), 1e300);
+        assert_eq!(tiny.into_inner(), -1e300);
+    }
+
+    // these pass through on either build
+    let inf = Temperature::from_f64_saturating(Unit::Celsius, f64::INFINITY);
+    assert_eq!(inf.into_inner(), Float::INFINITY);
+    assert!(Temperature::from_f64_saturating(Unit::Celsius, f64::NAN).is_nan());
+}
+
+#[test]
+fn map_temp_affine() {
+    let affine = |x: Float| 2.0 * x + 1.0;
+
+    let c = Temperature::Celsius(10.0).map_temp(affine);
+    assert_eq!(c, Temperature::Celsius(21.0));
+
+    // the unit always stays the same
+    let f = Temperature::Fahrenheit(-3.5).map_temp(affine);
+    assert_eq!(f, Temperature::Fahrenheit(-6.0));
+
+    let k = Temperature::Kelvin(300.0).map_temp(|x| x * 0.5 - 200.0);
+    assert_eq!(k.unit(), Unit::Kelvin);
+    assert_approx_eq!(k.into_inner(), -50.0);
+
+    // and nothing gets checked
+    assert!(k.is_below_abs_zero());
+    assert!(Temperature::Celsius(1.0).map_temp(|_| Float::NAN).is_nan());
+}
+
+#[test]
+fn fraction_between() {
+    let lower = Temperature::Celsius(0.0);
+    let upper = Temperature::Celsius(100.0);
+
+    assert_approx_eq!(lower.fraction_between(lower, upper), 0.0);
+    assert_approx_eq!(upper.fraction_between(lower, upper), 1.0);
+    assert_approx_eq!(
+        Temperature::Celsius(50.0).fraction_between(lower, upper),
+        0.5
+    );
+
+    // units can be mixed
+    assert_approx_eq!(
+        Temperature::Fahrenheit(122.0).fraction_between(Temperature::Kelvin(273.15), upper),
+        0.5,
+        1e-4
+    );
+
+    // outside the range isn't clamped
+    assert_approx_eq!(
+        Temperature::Celsius(-50.0).fraction_between(lower, upper),
+        -0.5
+    );
+    assert_approx_eq!(
+        Temperature::Celsius(150.0).fraction_between(lower, upper),
+        1.5
+    );
+
+    // an empty range
+    assert_eq!(
+        Temperature::Celsius(20.0).fraction_between(lower, Temperature::Kelvin(273.15)),
+        0.0
+    );
+}
+
+#[test]
+fn scale_to_range() {
+    let sensor = (Temperature::Celsius(0.0), Temperature::Celsius(50.0));
+    let gauge = (0.0, 100.0);
+
+    // endpoints and midpoint
+    assert_approx_eq!(sensor.0.scale_to_range(sensor, gauge), 0.0);
+    assert_approx_eq!(sensor.1.scale_to_range(sensor, gauge), 100.0);
+    assert_approx_eq!(
+        Temperature::Celsius(25.0).scale_to_range(sensor, gauge),
+        50.0
+    );
+
+    // the temperature can be in any unit
+    assert_approx_eq!(
+        Temperature::Fahrenheit(77.0).scale_to_range(sensor, gauge),
+        50.0,
+        1e-3
+    );
+
+    // clamped outside the source range
+    assert_eq!(
+        Temperature::Celsius(-10.0).scale_to_range(sensor, gauge),
+        0.0
+    );
+    assert_eq!(
+        Temperature::Celsius(60.0).scale_to_range(sensor, gauge),
+        100.0
+    );
+}
+
+#[test]
+fn scale_to_range_reversed_and_empty() {
+    let sensor = (Temperature::Celsius(0.0), Temperature::Celsius(50.0));
+
+    // a target range that runs backwards
+    assert_approx_eq!(
+        Temperature::Celsius(10.0).scale_to_range(sensor, (1.0, -1.0)),
+        0.6
+    );
+
+    // an empty source range
+    let empty = (Temperature::Celsius(20.0), Temperature::Kelvin(293.15));
+    assert_eq!(
+        Temperature::Celsius(30.0).scale_to_range(empty, (5.0, 10.0)),
+        5.0
+    );
+
+    assert!(Temperature::Celsius(Float::NAN)
+        .scale_to_range(sensor, (0.0, 1.0))
+        .is_nan());
+}
+
+#[test]
+fn quantize_to_ds18b20_steps() {
+    const STEP: Float = 0.0625;
+
+    assert_eq!(
+        Temperature::Celsius(25.03).quantize_to_sensor(STEP),
+        Temperature::Celsius(25.0)
+    );
+    assert_eq!(
+        Temperature::Celsius(25.04).quantize_to_sensor(STEP),
+        Temperature::Celsius(25.0625)
+    );
+    assert_eq!(
+        Temperature::Celsius(-10.03).quantize_to_sensor(STEP),
+        Temperature::Celsius(-10.0)
+    );
+
+    // already on a step
+    assert_eq!(
+        Temperature::Celsius(-55.0).quantize_to_sensor(STEP),
+        Temperature::Celsius(-55.0)
+    );
+
+    // the unit comes back the same, snapped to 25.0625 C
+    let quantized = Temperature::Fahrenheit(77.1).quantize_to_sensor(STEP);
+    assert!(matches!(quantized, Temperature::Fahrenheit(_)));
+    assert_approx_eq!(quantized.into_inner(), 77.1125, 1e-3);
+
+    let quantized = Temperature::Kelvin(298.2).quantize_to_sensor(STEP);
+    assert!(matches!(quantized, Temperature::Kelvin(_)));
+    assert_approx_eq!(quantized.to_celsius().into_inner(), 25.0625, 1e-3);
+}
+
+#[test]
+fn quantize_to_sensor_edges() {
+    let temp = Temperature::Celsius(21.3);
+
+    assert_eq!(temp.quantize_to_sensor(0.0), temp);
+    assert_eq!(temp.quantize_to_sensor(-0.5), temp);
+    assert_eq!(temp.quantize_to_sensor(Float::NAN), temp);
+
+    assert!(Temperature::Celsius(Float::NAN)
+        .quantize_to_sensor(0.5)
+        .is_nan());
+    assert_eq!(
+        Temperature::Celsius(Float::INFINITY).quantize_to_sensor(0.5),
+        Temperature::Celsius(Float::INFINITY)
+    );
+    assert_eq!(
+        Temperature::Celsius(Float::MAX).quantize_to_sensor(0.5),
+        Temperature::Celsius(Float::MAX)
+    );
+}
+
+#[test]
+fn delta_kelvin_across_units() {
+    let a = Temperature::Celsius(25.0);
+    let b = Temperature::Celsius(24.0);
+
+    // a 1 °C difference is a 1 K difference...
+    assert_approx_eq!(a.delta_kelvin(b), 1.0, 1e-4);
+    assert_approx_eq!(a.to_kelvin().delta_kelvin(b.to_kelvin()), 1.0, 1e-4);
+
+    // ...but 1.8 °F apart
+    let (a_f, b_f) = (a.to_fahrenheit(), b.to_fahrenheit());
+    assert_approx_eq!(a_f.into_inner() - b_f.into_inner(), 1.8, 1e-4);
+    assert_approx_eq!(a_f.delta_kelvin(b_f), 1.0, 1e-4);
+
+    // mixed units, and the sign follows `self - other`
+    assert_approx_eq!(
+        Temperature::Fahrenheit(212.0).delta_kelvin(Temperature::Kelvin(273.15)),
+        100.0,
+        1e-3
+    );
+    assert_approx_eq!(
+        Temperature::Kelvin(273.15).delta_kelvin(Temperature::Fahrenheit(212.0)),
+        -100.0,
+        1e-3
+    );
+    assert_eq!(a.delta_kelvin(a), 0.0);
+}
+
+#[test]
+fn delta_kelvin_differs_from_sub() {
+    let warm = Temperature::Celsius(30.0);
+    let cool = Temperature::Celsius(20.0);
+
+    // `-` gives a point on the Celsius scale...
+    let point = warm - cool;
+    assert_eq!(point, Temperature::Celsius(10.0));
+    assert_approx_eq!(point.to_kelvin().into_inner(), 283.15, 1e-3);
+
+    // ...while the delta is the span between them
+    assert_approx_eq!(warm.delta_kelvin(cool), 10.0, 1e-4);
+}
+
+#[test]
+fn try_conversions_finite() {
+    let boiling = Temperature::Celsius(100.0);
+
+    assert_eq!(boiling.try_to_celsius(), Ok(boiling));
+    assert_eq!(boiling.try_to_fahrenheit(), Ok(boiling.to_fahrenheit()));
+    assert_eq!(boiling.try_to_kelvin(), Ok(boiling.to_kelvin()));
+
+    for unit in [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin] {
+        assert_eq!(boiling.try_to_unit(unit), Ok(boiling.to_unit(unit)));
+    }
+}
+
+#[test]
+fn try_conversions_nan() {
+    let nan = Temperature::Fahrenheit(Float::NAN);
+
+    assert_eq!(nan.try_to_fahrenheit(), Err(TemperatureError::IsNan));
+    assert_eq!(nan.try_to_celsius(), Err(TemperatureError::IsNan));
+    assert_eq!(nan.try_to_kelvin(), Err(TemperatureError::IsNan));
+}
+
+#[test]
+fn try_conversions_infinite() {
+    let hot = Temperature::Kelvin(Float::INFINITY);
+    let cold = Temperature::Celsius(Float::NEG_INFINITY);
+
+    assert_eq!(
+        hot.try_to_celsius(),
+        Err(TemperatureError::IsInfinite(Float::INFINITY))
+    );
+    assert_eq!(
+        cold.try_to_kelvin(),
+        Err(TemperatureError::IsInfinite(Float::NEG_INFINITY))
+    );
+
+    // a finite input can still overflow on the way
+    assert_eq!(
+        Temperature::Celsius(Float::MAX).try_to_fahrenheit(),
+        Err(TemperatureError::IsInfinite(Float::INFINITY))
+    );
+    assert!(Temperature::Celsius(Float::MAX).try_to_kelvin().is_ok());
+}
@@ -0,0 +1,44 @@
+use simmer::{traced::TracedTemperature, Temperature, Unit};
+
+const UNITS: [Unit; 3] = [Unit::Fahrenheit, Unit::Celsius, Unit::Kelvin];
+
+#[test]
+fn starts_as_original() {
+    let reading = TracedTemperature::new(Temperature::Celsius(21.5));
+
+    assert_eq!(reading.original(), Temperature::Celsius(21.5));
+    assert_eq!(reading.current(), Temperature::Celsius(21.5));
+    assert_eq!(TracedTemperature::from(Temperature::Celsius(21.5)), reading);
+}
+
+#[test]
+fn conversions_rederive_from_original() {
+    let original = Temperature::Fahrenheit(98.6);
+    let mut traced = TracedTemperature::new(original);
+
+    // bounce around a bunch
+    for _ in 0..100 {
+        for unit in UNITS {
+            traced = traced.to_unit(unit);
+
+            assert_eq!(traced.original(), original);
+            assert_eq!(traced.current(), original.to_unit(unit));
+        }
+    }
+
+    // ending up back home gives the exact original value
+    assert_eq!(traced.to_fahrenheit().current(), original);
+    assert_eq!(Temperature::from(traced.to_fahrenheit()), original);
+}
+
+#[test]
+fn named_conversions() {
+    let original = Temperature::Kelvin(300.0);
+    let traced = TracedTemperature::new(original)
+        .to_celsius()
+        .to_fahrenheit();
+
+    assert_eq!(traced.current(), original.to_fahrenheit());
+    assert_eq!(traced.to_kelvin().current(), original);
+    assert_eq!(traced.to_celsius().current(), original.to_celsius());
+}
@@ -0,0 +1,152 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{
+    tracking::{DecayingPeak, RateTracker},
+    Temperature,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+#[test]
+fn first_sample_has_no_rate() {
+    let mut tracker = RateTracker::new();
+    assert_eq!(tracker.update(Temperature::Kelvin(300.0), 0.0), None);
+}
+
+#[test]
+fn linear_ramp() {
+    let mut tracker = RateTracker::new();
+
+    // heating at 0.25 K/s, sampled every 4 seconds
+    for step in 0..10 {
+        let time = step as Float * 4.0;
+        let temp = Temperature::Celsius(20.0 + step as Float);
+
+        let rate = tracker.update(temp, time);
+
+        if step == 0 {
+            assert_eq!(rate, None);
+        } else {
+            assert_approx_eq!(rate.unwrap(), 0.25, 1e-4);
+        }
+    }
+}
+
+#[test]
+fn mixed_units_are_normalized() {
+    let mut tracker = RateTracker::new();
+
+    tracker.update(Temperature::Celsius(0.0), 0.0);
+
+    // 33.8 F is 1 C, so that's 1 K in one second
+    let rate = tracker.update(Temperature::Fahrenheit(33.8), 1.0).unwrap();
+    assert_approx_eq!(rate, 1.0, 1e-4);
+}
+
+#[test]
+fn cooling_is_negative() {
+    let mut tracker = RateTracker::new();
+
+    tracker.update(Temperature::Kelvin(310.0), 0.0);
+    let rate = tracker.update(Temperature::Kelvin(300.0), 5.0).unwrap();
+
+    assert_approx_eq!(rate, -2.0);
+}
+
+#[test]
+fn bad_time_deltas() {
+    let mut tracker = RateTracker::new();
+    tracker.update(Temperature::Kelvin(300.0), 10.0);
+
+    // zero and negative deltas don't report anything...
+    assert_eq!(tracker.update(Temperature::Kelvin(301.0), 10.0), None);
+    assert_eq!(tracker.update(Temperature::Kelvin(302.0), 0.0), None);
+
+    // ...but the tracker recovers from the latest reading
+    let rate = tracker.update(Temperature::Kelvin(304.0), 1.0).unwrap();
+    assert_approx_eq!(rate, 2.0);
+}
+
+#[test]
+fn reset() {
+    let mut tracker = RateTracker::new();
+    tracker.update(Temperature::Kelvin(300.0), 0.0);
+
+    tracker.reset();
+    assert_eq!(tracker, RateTracker::default());
+    assert_eq!(tracker.update(Temperature::Kelvin(301.0), 1.0), None);
+}
+
+#[test]
+fn peak_spike_then_decay() {
+    let mut peak = DecayingPeak::new(Temperature::Celsius(20.0));
+
+    // a spike is caught right away
+    peak.update(Temperature::Celsius(50.0), 0.25);
+    assert_approx_eq!(peak.peak().into_inner(), 50.0, 1e-4);
+
+    // back to normal: the peak sags by a quarter of the gap each time
+    let mut expected = 50.0;
+    for _ in 0..20 {
+        peak.update(Temperature::Celsius(20.0), 0.25);
+        expected -= 0.25 * (expected - 20.0);
+
+        assert_approx_eq!(peak.peak().into_inner(), expected, 1e-3);
+    }
+
+    // and it's all but forgotten
+    assert!(peak.peak().into_inner() - 20.0 < 0.1);
+}
+
+#[test]
+fn peak_decay_extremes() {
+    let mut held = DecayingPeak::new(Temperature::Kelvin(400.0));
+    let mut follows = DecayingPeak::new(Temperature::Kelvin(400.0));
+
+    for _ in 0..5 {
+        held.update(Temperature::Kelvin(300.0), 0.0);
+        follows.update(Temperature::Kelvin(300.0), 1.0);
+    }
+    assert_eq!(held.peak(), Temperature::Kelvin(400.0));
+    assert_eq!(follows.peak(), Temperature::Kelvin(300.0));
+
+    // out-of-range and `NaN` decays are clamped
+    held.update(Temperature::Kelvin(300.0), -3.0);
+    held.update(Temperature::Kelvin(300.0), Float::NAN);
+    assert_eq!(held.peak(), Temperature::Kelvin(400.0));
+
+    follows.update(Temperature::Kelvin(200.0), 7.0);
+    assert_eq!(follows.peak(), Temperature::Kelvin(200.0));
+}
+
+#[test]
+fn peak_reports_latest_unit() {
+    let mut peak = DecayingPeak::new(Temperature::Celsius(100.0));
+
+    peak.update(Temperature::Fahrenheit(32.0), 0.0);
+    let reported = peak.peak();
+
+    assert!(matches!(reported, Temperature::Fahrenheit(_)));
+    assert_approx_eq!(reported.into_inner(), 212.0, 1e-3);
+
+    // higher in a different unit still counts
+    peak.update(Temperature::Kelvin(400.0), 0.0);
+    assert_approx_eq!(peak.peak().into_inner(), 400.0, 1e-3);
+}
+
+#[test]
+fn peak_ignores_nan() {
+    let mut peak = DecayingPeak::new(Temperature::Celsius(30.0));
+
+    peak.update(Temperature::Kelvin(Float::NAN), 1.0);
+    assert_eq!(peak.peak(), Temperature::Celsius(30.0));
+
+    // a `NaN` start gets replaced by the first real reading
+    let mut peak = DecayingPeak::new(Temperature::Celsius(Float::NAN));
+    peak.update(Temperature::Celsius(10.0), 0.5);
+    assert_approx_eq!(peak.peak().into_inner(), 10.0, 1e-4);
+}
@@ -0,0 +1,115 @@
+use assert_approx_eq::assert_approx_eq;
+use simmer::{
+    typed::{Celsius, Fahrenheit, Kelvin, TypedTemp, UnitMarker},
+    Temperature, Unit,
+};
+
+// just like in the lib itself...
+#[cfg(not(feature = "f32"))]
+type Float = f64;
+
+#[cfg(feature = "f32")]
+type Float = f32;
+
+const VALUES: [Float; 6] = [-459.67, -40.0, 0.0, 37.0, 100.0, 5500.0];
+
+/// Converts `value` from `U` to `V` both ways, and makes sure they agree
+/// (up to rounding).
+fn matches_enum<U: UnitMarker, V: UnitMarker>(value: Float) {
+    let typed = TypedTemp::<U>::new(value).convert::<V>();
+    let runtime = Temperature::from((value, U::UNIT)).to_unit(V::UNIT);
+
+    // offsets go up to ~460, and can cancel down to zero, so the rounding
+    // error scales with the input plus the offset, not the output
+    let tolerance = (value.abs() + 500.0) * Float::EPSILON * 8.0;
+    assert_approx_eq!(typed.value(), runtime.into_inner(), tolerance);
+    assert_eq!(typed.unit(), V::UNIT);
+}
+
+#[test]
+fn same_unit_is_exact() {
+    for value in VALUES {
+        assert_eq!(
+            TypedTemp::<Kelvin>::new(value).convert::<Kelvin>().value(),
+            value
+        );
+        assert_eq!(
+            TypedTemp::<Fahrenheit>::new(value)
+                .convert::<Fahrenheit>()
+                .value(),
+            value
+        );
+    }
+}
+
+#[test]
+fn conversions_match_the_enum() {
+    for value in VALUES {
+        matches_enum::<Fahrenheit, Fahrenheit>(value);
+        matches_enum::<Fahrenheit, Celsius>(value);
+        matches_enum::<Fahrenheit, Kelvin>(value);
+
+        matches_enum::<Celsius, Fahrenheit>(value);
+        matches_enum::<Celsius, Celsius>(value);
+        matches_enum::<Celsius, Kelvin>(value);
+
+        matches_enum::<Kelvin, Fahrenheit>(value);
+        matches_enum::<Kelvin, Celsius>(value);
+        matches_enum::<Kelvin, Kelvin>(value);
+    }
+}
+
+#[test]
+fn known_values() {
+    let boiling = TypedTemp::<Celsius>::new(100.0);
+
+    assert_approx_eq!(boiling.convert::<Fahrenheit>().value(), 212.0);
+    assert_approx_eq!(boiling.convert::<Kelvin>().value(), 373.15, 1e-3);
+    assert_approx_eq!(
+        TypedTemp::<Fahrenheit>::new(-40.0)
+            .convert::<Celsius>()
+            .value(),
+        -40.0,
+        1e-4
+    );
+}
+
+#[test]
+fn to_and_from_temperature() {
+    let typed = TypedTemp::<Kelvin>::new(300.0);
+
+    assert_eq!(typed.unit(), Unit::Kelvin);
+    assert_eq!(Temperature::from(typed), Temperature::Kelvin(300.0));
+
+    // coming in from a `Temperature` converts it
+    let from_f: TypedTemp<Celsius> = Temperature::Fahrenheit(212.0).into();
+    assert_approx_eq!(from_f.value(), 100.0, 1e-4);
+    assert_eq!(
+        TypedTemp::<Celsius>::from_temperature(Temperature::Celsius(12.5)),
+        TypedTemp::new(12.5)
+    );
+}
+
+#[test]
+fn arithmetic_within_a_unit() {
+    let a = TypedTemp::<Fahrenheit>::new(70.0);
+    let b = TypedTemp::<Fahrenheit>::new(5.0);
+
+    assert_eq!((a + b).value(), 75.0);
+    assert_eq!((a - b).value(), 65.0);
+    assert!(b < a);
+
+    // matches adding the enums when the units agree
+    assert_eq!(
+        (a + b).to_temperature(),
+        a.to_temperature() + b.to_temperature()
+    );
+}
+
+#[test]
+fn nan_carries_through() {
+    let nan = TypedTemp::<Celsius>::new(Float::NAN);
+
+    assert!(nan.convert::<Kelvin>().value().is_nan());
+    assert!(nan.to_temperature().is_nan());
+}
@@ -1,4 +1,4 @@
-use simmer::Temperature;
+use simmer::{display::PrecisionTemp, Temperature};
 use util::CharArrWriter;
 
 extern crate alloc;
@@ -57,3 +57,108 @@ fn ufmt_debug_print() {
             .trim()
     );
 }
+
+/// Writes a temperature padded to `WIDTH` with `uwrite_padded`.
+struct Padded<const WIDTH: usize>(Temperature);
+
+impl<const WIDTH: usize> ufmt::uDisplay for Padded<WIDTH> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt_write::uWrite + ?Sized,
+    {
+        self.0.uwrite_padded(f, WIDTH)
+    }
+}
+
+#[test]
+fn ufmt_padded_width() {
+    let mut buf = CharArrWriter::default();
+
+    ufmt::uwrite!(&mut buf, "{}|", Padded::<10>(Temperature::Celsius(0.0))).unwrap();
+
+    let out = buf
+        .to_char_iter()
+        .copied()
+        .collect::<alloc::string::String>();
+    let out = out.trim_end();
+
+    assert_eq!(out, "   0.00000|");
+    assert_eq!(out.len(), 10 + 1);
+}
+
+#[test]
+fn ufmt_padded_columns_line_up() {
+    let mut buf = CharArrWriter::default();
+
+    ufmt::uwrite!(
+        &mut buf,
+        "{}|{}|",
+        Padded::<12>(Temperature::Kelvin(5778.0)),
+        Padded::<12>(Temperature::Fahrenheit(-40.0))
+    )
+    .unwrap();
+
+    assert_eq!(
+        buf.to_char_iter()
+            .copied()
+            .collect::<alloc::string::String>()
+            .trim_end(),
+        "  5778.00000|   -40.00000|"
+    );
+}
+
+#[test]
+fn ufmt_padded_never_truncates() {
+    let mut buf = CharArrWriter::default();
+
+    ufmt::uwrite!(&mut buf, "{}", Padded::<2>(Temperature::Celsius(42.13))).unwrap();
+
+    assert_eq!(
+        buf.to_char_iter()
+            .copied()
+            .collect::<alloc::string::String>()
+            .trim_end(),
+        "42.13000"
+    );
+}
+
+/// Writes `PrecisionTemp(temp, precision)` and returns the output.
+fn with_precision(temp: Temperature, precision: u8) -> alloc::string::String {
+    let mut buf = CharArrWriter::default();
+
+    ufmt::uwrite!(&mut buf, "{}", PrecisionTemp(temp, precision)).unwrap();
+
+    buf.to_char_iter()
+        .copied()
+        .collect::<alloc::string::String>()
+        .trim()
+        .into()
+}
+
+#[test]
+fn ufmt_precision_one_through_five() {
+    let temp = Temperature::Celsius(21.5);
+
+    assert_eq!(with_precision(temp, 1), "21.5");
+    assert_eq!(with_precision(temp, 2), "21.50");
+    assert_eq!(with_precision(temp, 3), "21.500");
+    assert_eq!(with_precision(temp, 4), "21.5000");
+    assert_eq!(with_precision(temp, 5), "21.50000");
+}
+
+#[test]
+fn ufmt_precision_small_fractions() {
+    let temp = Temperature::Kelvin(0.125);
+
+    assert_eq!(with_precision(temp, 1), "0.1");
+    assert_eq!(with_precision(temp, 2), "0.12");
+    assert_eq!(with_precision(temp, 3), "0.125");
+}
+
+#[test]
+fn ufmt_precision_is_clamped() {
+    let temp = Temperature::Fahrenheit(98.25);
+
+    assert_eq!(with_precision(temp, 0), "98.2");
+    assert_eq!(with_precision(temp, 200), "98.25000");
+}
@@ -6,7 +6,7 @@ pub(crate) struct CharArrWriter {
 impl CharArrWriter {
     #[allow(unused)]
     pub(crate) fn to_char_iter(&self) -> impl Iterator<Item = &char> {
-        return self.data.iter();
+        self.data.iter()
     }
 
     #[allow(unused)]